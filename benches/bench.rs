@@ -144,6 +144,24 @@ fn bench_mul_large(c: &mut Criterion) {
     });
 }
 
+fn bench_pow_small(c: &mut Criterion) {
+    let m = matrix_small();
+    c.bench_function("pow_small", move |b| {
+        b.iter(|| {
+            black_box(m.pow(1000));
+        })
+    });
+}
+
+fn bench_pow_large(c: &mut Criterion) {
+    let m = matrix_large();
+    c.bench_function("pow_large", move |b| {
+        b.iter(|| {
+            black_box(m.pow(1000));
+        })
+    });
+}
+
 criterion_group!(
     bench_basic,
     bench_get_row_small,
@@ -162,6 +180,8 @@ criterion_group!(
     bench_sub_large,
     bench_mul_small,
     bench_mul_large,
+    bench_pow_small,
+    bench_pow_large,
 );
 
 criterion_main!(bench_basic, bench_std_ops);
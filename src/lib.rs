@@ -98,11 +98,87 @@ simple-matrix = { version = "0.1", features = ["impl_from"] }
 Current available features are listed below with a little description:
 ## impl_from
 Implements the *From* Trait for basic numeric types.
+Other conversions, including narrowing and user types, are always available
+with `Matrix::cast`, `Matrix::try_cast`, `Matrix::saturating_cast` and `Matrix::round_cast`.
 
 ```
 let m1: Matrix<i8> = Matrix::new(3, 5);
 let m2: Matrix<i64> = m1.into();
 ```
+
+## approx
+Implements the *AbsDiffEq*, *RelativeEq* and *UlpsEq* traits of the [approx](https://crates.io/crates/approx) crate.
+
+```
+let m1: Matrix<f64> = Matrix::from_iter(1, 2, vec![0.1 + 0.2, 1.0]);
+let m2: Matrix<f64> = Matrix::from_iter(1, 2, vec![0.3, 1.0]);
+assert_relative_eq!(m1, m2);
+```
+
+## csv
+Reads and writes matrices as CSV data, with `Matrix::read_csv` and `Matrix::write_csv`.
+
+```
+let data = "a,b\n1,2\n3,NA\n";
+let m: Matrix<f64> = Matrix::read_csv(data.as_bytes(), &CsvReadOptions {
+    header_rows: 1,
+    missing_tokens: vec!["NA".to_string()],
+    missing: MissingValues::Fill(f64::NAN),
+    ..CsvReadOptions::default()
+})?;
+m.write_csv(std::io::stdout(), &CsvWriteOptions::default())?;
+```
+
+## npz
+Reads named arrays from the `.npz` archives written by `numpy.savez`, with `Matrix::read_npz`.
+Single `.npy` files are always supported, with `Matrix::read_npy` and `Matrix::write_npy`.
+
+```
+let file = std::fs::File::open("model.npz")?;
+let weights: Matrix<f32> = Matrix::read_npz(file, "weights")?;
+```
+
+## serde
+Implements *Serialize* and *Deserialize* from the [serde](https://crates.io/crates/serde) crate.
+Matrices are serialized as `{"rows": 2, "cols": 2, "data": [1, 2, 3, 4]}`,
+or as nested rows `[[1, 2], [3, 4]]` with the `nested_rows` module.
+
+```
+#[derive(Serialize, Deserialize)]
+struct Config {
+    transform: Matrix<f64>,
+    #[serde(with = "simple_matrix::nested_rows")]
+    weights: Matrix<f64>,
+}
+```
+
+## mmap
+Maps files written by `Matrix::save` into memory with `MmapMatrix` (read-only) and `MmapMatrixMut` (read-write),
+to process matrices larger than memory tile by tile.
+
+```
+let mat: MmapMatrix<f64> = MmapMatrix::open("huge.smx")?;
+let tile = mat.tile(0, 0, 1024, 1024);
+let trace: f64 = (0..1024).map(|i| tile[(i, i)]).sum();
+```
+
+## ndarray
+Views matrices as `ndarray` arrays without copying them, with `Matrix::as_ndarray` and `Matrix::as_ndarray_mut`,
+and converts them from and into `Array2` with *From*.
+
+```
+let array: ndarray::Array2<f64> = mat.clone().into();
+let mat: Matrix<f64> = array.t().to_owned().into();
+```
+
+## nalgebra
+Views matrices as `nalgebra` matrices without copying them, with `Matrix::as_nalgebra` and `Matrix::as_nalgebra_mut`,
+and converts them from and into `DMatrix` with *From*.
+
+```
+let det = mat.as_nalgebra().determinant();
+let dmat: nalgebra::DMatrix<f64> = mat.into();
+```
 */
 
 #![deny(missing_docs)]
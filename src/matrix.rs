@@ -1,11 +1,73 @@
+#[cfg(feature = "approx")]
+mod approx;
+mod approx_eq;
+mod banded;
+mod binary;
+mod bytes;
+mod cast;
+#[cfg(feature = "csv")]
+mod csv;
+mod dense;
+mod display;
+mod exact;
 #[cfg(feature = "impl_from")]
 mod from;
+mod funcs;
 mod iter;
+pub mod iterative;
+mod market;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+mod norm;
+mod npy;
+mod num;
+mod octave;
+mod pow;
+mod render;
+mod resize;
+#[cfg(feature = "serde")]
+mod serde;
+mod sparse;
+pub mod special;
+mod stack;
 mod std_ops;
+mod structured;
+mod svd;
+mod table;
+
+#[doc(hidden)]
+pub use self::approx_eq::{assert_matrix_approx_eq_impl, ApproxTolerance};
+pub use self::banded::{BandedLu, BandedMatrix};
+pub use self::binary::{BinaryElement, BinaryError};
+pub use self::cast::{CastError, FloatCast};
+#[cfg(feature = "csv")]
+pub use self::csv::{CsvError, CsvReadOptions, CsvWriteOptions, MissingValues};
+pub use self::display::{DisplayOptions, DisplayStyle, MatrixDisplay};
+pub use self::funcs::MatrixFunctionError;
+pub use self::market::{MatrixMarketError, MatrixMarketField, MatrixMarketSymmetry};
+#[cfg(feature = "mmap")]
+pub use self::mmap::{MmapMatrix, MmapMatrixMut, MmapTile};
+pub use self::norm::Norm;
+pub use self::npy::{NpyElement, NpyError};
+pub use self::num::{Field, Real, Ring};
+pub use self::octave::ParseMatrixError;
+pub use self::render::{Colormap, RenderOptions, ValueRange};
+#[cfg(feature = "serde")]
+pub use self::serde::nested_rows;
+pub use self::sparse::{CooMatrix, CscMatrix, CsrMatrix};
+pub use self::stack::Axis;
+pub use self::structured::{Diagonal, LowerTriangular, Permutation, Symmetric, UpperTriangular};
+pub use self::svd::Svd;
+pub use self::table::{CellFormatter, LatexEnvironment, TableOptions};
 
 use std::ops::{Deref, Index, IndexMut};
 
-/// A 2-Dimensional, non-resisable container.
+/// A 2-Dimensional, non-empty container, stored row by row.  
+/// Rows and columns can be inserted and removed, see `Matrix::insert_row` and `Matrix::resize`.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd)]
 pub struct Matrix<T> {
     rows: usize,
@@ -208,8 +270,8 @@ impl<T> Matrix<T> {
             cols: self.rows,
             data: {
                 let mut data = Vec::with_capacity(self.cols * self.rows);
-                for row in 0..self.rows {
-                    for val in self.get_row(row).unwrap() {
+                for col in 0..self.cols {
+                    for val in self.get_col(col).unwrap() {
                         data.push(val.clone());
                     }
                 }
@@ -231,8 +293,8 @@ impl<T> Matrix<T> {
     ///
     /// assert_eq!(sum, 153);
     /// ```
-    pub fn apply<F: FnMut(&T)>(&self, mut func: F) {
-        self.data.iter().for_each(|n| func(n));
+    pub fn apply<F: FnMut(&T)>(&self, func: F) {
+        self.data.iter().for_each(func);
     }
 
     /// Apply a function to all cells of the matrix.  
@@ -249,8 +311,8 @@ impl<T> Matrix<T> {
     /// assert_eq!(mat.get(0, 1).unwrap(), 2);
     /// assert_eq!(mat.get(0, 2).unwrap(), 4);
     /// ```
-    pub fn apply_mut<F: FnMut(&mut T)>(&mut self, mut func: F) {
-        self.data.iter_mut().for_each(|n| func(n));
+    pub fn apply_mut<F: FnMut(&mut T)>(&mut self, func: F) {
+        self.data.iter_mut().for_each(func);
     }
 }
 
@@ -0,0 +1,65 @@
+use super::Matrix;
+use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+// Matrices are compared cell by cell, and are never equal if their shapes differ
+
+impl<T: AbsDiffEq> AbsDiffEq for Matrix<T>
+where
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for Matrix<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<T: UlpsEq> UlpsEq for Matrix<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
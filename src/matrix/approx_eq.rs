@@ -0,0 +1,253 @@
+use super::{Matrix, Real};
+use std::fmt::Debug;
+
+impl<T: Real> Matrix<T> {
+    /// Returns `true` if both matrices have the same shape
+    /// and all their cells are approximately equal.
+    /// Two cells `a` and `b` are approximately equal if
+    /// `|a - b| <= max(abs_tol, rel_tol * max(|a|, |b|))`.
+    ///
+    /// # Examples
+    /// ```
+    /// let a: Matrix<f64> = Matrix::from_iter(1, 2, vec![1.0, 100.0]);
+    /// let b: Matrix<f64> = Matrix::from_iter(1, 2, vec![1.0 + 1e-12, 100.0 + 1e-9]);
+    ///
+    /// assert!(a.approx_eq(&b, 1e-10, 1e-10));
+    /// assert!(!a.approx_eq(&b, 1e-10, 0.0));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix<T>, abs_tol: T, rel_tol: T) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .all(|(&a, &b)| cell_approx_eq(a, b, abs_tol, rel_tol))
+    }
+
+    /// Returns `true` if both matrices have the same shape
+    /// and all their cells are at most `max_ulps` representable values apart.
+    ///
+    /// # Examples
+    /// ```
+    /// let a: Matrix<f64> = Matrix::from_iter(1, 2, vec![1.0, 2.0]);
+    /// let b: Matrix<f64> = Matrix::from_iter(1, 2, vec![1.0 + f64::EPSILON, 2.0]);
+    ///
+    /// assert!(a.ulps_eq(&b, 1));
+    /// assert!(!a.ulps_eq(&b, 0));
+    /// ```
+    pub fn ulps_eq(&self, other: &Matrix<T>, max_ulps: u32) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .all(|(&a, &b)| a.ulps_distance(b) <= u64::from(max_ulps))
+    }
+
+    /// Returns the positions (row, column) of the cells
+    /// that are not approximately equal, as defined by `approx_eq`.
+    ///
+    /// # Panics
+    /// Panics if the matrices do not have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// let a: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let b: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, 2.0, 3.5, 4.0]);
+    ///
+    /// assert_eq!(a.approx_mismatches(&b, 1e-9, 1e-9), vec![(1, 0)]);
+    /// ```
+    pub fn approx_mismatches(
+        &self,
+        other: &Matrix<T>,
+        abs_tol: T,
+        rel_tol: T,
+    ) -> Vec<(usize, usize)> {
+        assert!(self.rows == other.rows);
+        assert!(self.cols == other.cols);
+
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .enumerate()
+            .filter(|(_, (&a, &b))| !cell_approx_eq(a, b, abs_tol, rel_tol))
+            .map(|(i, _)| (i / self.cols, i % self.cols))
+            .collect()
+    }
+}
+
+fn cell_approx_eq<T: Real>(a: T, b: T, abs_tol: T, rel_tol: T) -> bool {
+    // Handles infinities of the same sign
+    if a == b {
+        return true;
+    }
+
+    let diff = (a - b).abs();
+    diff <= abs_tol || diff <= rel_tol * a.abs().max(b.abs())
+}
+
+/// Tolerance used by `assert_matrix_approx_eq!`.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub enum ApproxTolerance<T> {
+    /// Absolute and relative tolerances, as in `Matrix::approx_eq`.
+    AbsRel(T, T),
+    /// Maximum distance in ULPs, as in `Matrix::ulps_eq`.
+    Ulps(u32),
+}
+
+/// Implementation of `assert_matrix_approx_eq!`.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_matrix_approx_eq_impl<T: Real>(
+    left: &Matrix<T>,
+    right: &Matrix<T>,
+    tol: ApproxTolerance<T>,
+) {
+    let (equal, tol_desc) = match tol {
+        ApproxTolerance::AbsRel(abs_tol, rel_tol) => (
+            left.approx_eq(right, abs_tol, rel_tol),
+            format!("abs_tol: {:?}, rel_tol: {:?}", abs_tol, rel_tol),
+        ),
+        ApproxTolerance::Ulps(max_ulps) => {
+            (left.ulps_eq(right, max_ulps), format!("ulps: {}", max_ulps))
+        }
+    };
+
+    if equal {
+        return;
+    }
+
+    if left.rows != right.rows || left.cols != right.cols {
+        panic!(
+            "assertion failed: `left ≈ right` ({})\nshapes differ: left is {}x{}, right is {}x{}",
+            tol_desc, left.rows, left.cols, right.rows, right.cols
+        );
+    }
+
+    let mismatches = match tol {
+        ApproxTolerance::AbsRel(abs_tol, rel_tol) => {
+            left.approx_mismatches(right, abs_tol, rel_tol)
+        }
+        ApproxTolerance::Ulps(max_ulps) => left
+            .data
+            .iter()
+            .zip(right.data.iter())
+            .enumerate()
+            .filter(|(_, (&a, &b))| a.ulps_distance(b) > u64::from(max_ulps))
+            .map(|(i, _)| (i / left.cols, i % left.cols))
+            .collect(),
+    };
+
+    panic!(
+        "assertion failed: `left ≈ right` ({})\n{}",
+        tol_desc,
+        approx_report(left, right, &mismatches)
+    );
+}
+
+/// Lists the offending cells, then prints both matrices side by side,
+/// with the offending cells marked by a `*`.
+fn approx_report<T: Debug>(
+    left: &Matrix<T>,
+    right: &Matrix<T>,
+    mismatches: &[(usize, usize)],
+) -> String {
+    const MAX_LISTED: usize = 10;
+
+    let mut report = format!(
+        "{} of {} cells differ:\n",
+        mismatches.len(),
+        left.data.len()
+    );
+    for &(row, col) in mismatches.iter().take(MAX_LISTED) {
+        report += &format!(
+            "    ({}, {}): left = {:?}, right = {:?}\n",
+            row,
+            col,
+            left[(row, col)],
+            right[(row, col)]
+        );
+    }
+    if mismatches.len() > MAX_LISTED {
+        report += &format!("    ... and {} more\n", mismatches.len() - MAX_LISTED);
+    }
+
+    let render = |mat: &Matrix<T>| -> Vec<String> {
+        let cells: Vec<String> = (0..mat.data.len())
+            .map(|i| {
+                let mark = if mismatches.contains(&(i / mat.cols, i % mat.cols)) {
+                    "*"
+                } else {
+                    " "
+                };
+                format!("{}{:?}", mark, mat.data[i])
+            })
+            .collect();
+        let width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(0);
+
+        cells
+            .chunks(mat.cols)
+            .map(|row| {
+                let row: Vec<String> = row
+                    .iter()
+                    .map(|c| format!("{:>width$}", c, width = width))
+                    .collect();
+                format!("[{} ]", row.join(" "))
+            })
+            .collect()
+    };
+
+    let left = render(left);
+    let right = render(right);
+    let width = left[0].chars().count();
+
+    report += &format!("{:<width$}   right:\n", "left:", width = width);
+    for (l, r) in left.iter().zip(right.iter()) {
+        report += &format!("{}   {}\n", l, r);
+    }
+    report
+}
+
+/// Asserts that two floating-point matrices are approximately equal.
+/// On failure, the offending cells are listed
+/// and both matrices are printed side by side.
+///
+/// The tolerances default to the machine epsilon.
+/// They can be given explicitly, with `abs_tol` and `rel_tol` as in `Matrix::approx_eq`,
+/// or replaced by a maximum distance in ULPs, as in `Matrix::ulps_eq`.
+///
+/// # Examples
+/// ```
+/// let a: Matrix<f64> = Matrix::from_iter(1, 2, vec![0.1 + 0.2, 1.0]);
+/// let b: Matrix<f64> = Matrix::from_iter(1, 2, vec![0.3, 1.0]);
+///
+/// assert_matrix_approx_eq!(a, b);
+/// assert_matrix_approx_eq!(a, b, 1e-12, 0.0);
+/// assert_matrix_approx_eq!(a, b, ulps = 1);
+/// ```
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_matrix_approx_eq!(
+            $left,
+            $right,
+            $crate::Real::epsilon(),
+            $crate::Real::epsilon()
+        )
+    };
+
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        $crate::assert_matrix_approx_eq_impl(&$left, &$right, $crate::ApproxTolerance::Ulps($ulps))
+    };
+
+    ($left:expr, $right:expr, $abs_tol:expr, $rel_tol:expr $(,)?) => {
+        $crate::assert_matrix_approx_eq_impl(
+            &$left,
+            &$right,
+            $crate::ApproxTolerance::AbsRel($abs_tol, $rel_tol),
+        )
+    };
+}
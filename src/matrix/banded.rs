@@ -0,0 +1,400 @@
+use super::iterative::LinearOperator;
+use super::{Matrix, Real, Ring};
+
+/// A square band matrix, only storing the cells of its `lower` subdiagonals,
+/// its main diagonal and its `upper` superdiagonals.
+///
+/// Each row stores `lower + upper + 1` cells, so a *N*x*N* matrix
+/// uses `O(N * (lower + upper))` memory instead of `O(N²)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BandedMatrix<T> {
+    len: usize,
+    lower: usize,
+    upper: usize,
+    /// Row `i` stores the columns `i - lower..=i + upper`,
+    /// with zeros for the columns outside of the matrix.
+    data: Vec<T>,
+}
+
+/// LU factorization with partial pivoting of a band matrix,
+/// as returned by `BandedMatrix::lu`.
+///
+/// Row interchanges widen `U` to `lower + upper` superdiagonals,
+/// but the factorization still takes `O(N * lower * (lower + upper))` operations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedLu<T> {
+    len: usize,
+    lower: usize,
+    /// Number of superdiagonals of `U`.
+    upper: usize,
+    /// Row `i` of `U` stores the columns `i..=i + upper`.
+    u: Vec<T>,
+    /// Column `k` of `L` stores the `lower` multipliers below the diagonal.
+    l: Vec<T>,
+    /// Row `k` was interchanged with row `pivots[k]` at step `k`.
+    pivots: Vec<usize>,
+}
+
+impl<T> BandedMatrix<T> {
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the amount of columns the matrix has, which is the same as its amount of rows.
+    pub fn cols(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of subdiagonals of the band.
+    pub fn lower(&self) -> usize {
+        self.lower
+    }
+
+    /// Returns the number of superdiagonals of the band.
+    pub fn upper(&self) -> usize {
+        self.upper
+    }
+
+    /// Index of a cell in `data`, if it is inside the matrix and its band.
+    fn index(&self, row: usize, col: usize) -> Option<usize> {
+        if row < self.len && col < self.len && col + self.lower >= row && col <= row + self.upper {
+            Some(col + self.lower - row + row * (self.lower + self.upper + 1))
+        } else {
+            None
+        }
+    }
+
+    /// Try to get a reference to the value at given row & column.
+    /// Returns `None` if `row` or `col` is outside of the matrix or its band.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index(row, col).map(move |i| &self.data[i])
+    }
+
+    /// Try to get a mutable reference to the cell at given row & column.
+    /// Returns `None` if `row` or `col` is outside of the matrix or its band.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index(row, col).map(move |i| &mut self.data[i])
+    }
+
+    /// Try to set the cell at given row & column to the given value.
+    /// Returns `false` if `row` or `col` is outside of the matrix or its band.
+    /// Returns `true` if the cell has been modified.
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> bool {
+        if let Some(cell) = self.get_mut(row, col) {
+            *cell = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Columns of a row which are inside both the matrix and the band.
+    fn band(&self, row: usize) -> std::ops::Range<usize> {
+        row.saturating_sub(self.lower)..(row + self.upper + 1).min(self.len)
+    }
+}
+
+impl<T: Ring> BandedMatrix<T> {
+    /// Constructs a new *N*x*N* band matrix, with all cells set to zero.
+    ///
+    /// # Panics
+    /// Panics if `len` is equal to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: BandedMatrix<f64> = BandedMatrix::new(5, 1, 2);
+    ///
+    /// assert!(mat.set(0, 2, 1.0));
+    /// assert!(!mat.set(0, 3, 1.0));
+    /// ```
+    pub fn new(len: usize, lower: usize, upper: usize) -> BandedMatrix<T> {
+        assert!(len > 0);
+
+        BandedMatrix {
+            len,
+            lower,
+            upper,
+            data: vec![T::zero(); len * (lower + upper + 1)],
+        }
+    }
+
+    /// Constructs a tridiagonal matrix from its subdiagonal,
+    /// its main diagonal and its superdiagonal.
+    ///
+    /// # Panics
+    /// Panics if `diag` is empty,
+    /// or if `sub` and `sup` do not have one value less than `diag`.
+    ///
+    /// # Examples
+    /// ```
+    /// // 1-D Laplacian
+    /// let mat = BandedMatrix::tridiagonal(&[-1.0; 4], &[2.0; 5], &[-1.0; 4]);
+    ///
+    /// assert_eq!(mat.get(1, 0), Some(&-1.0));
+    /// ```
+    pub fn tridiagonal(sub: &[T], diag: &[T], sup: &[T]) -> BandedMatrix<T> {
+        let len = diag.len();
+        assert!(sub.len() + 1 == len && sup.len() + 1 == len);
+
+        let mut mat = BandedMatrix::new(len, 1, 1);
+        for i in 0..len {
+            mat.data[1 + i * 3] = diag[i].clone();
+            if i > 0 {
+                mat.data[i * 3] = sub[i - 1].clone();
+                mat.data[2 + (i - 1) * 3] = sup[i - 1].clone();
+            }
+        }
+        mat
+    }
+
+    /// Convert the matrix to a dense `Matrix`.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data = vec![T::zero(); self.len * self.len];
+        for row in 0..self.len {
+            for col in self.band(row) {
+                data[col + row * self.len] = self.get(row, col).unwrap().clone();
+            }
+        }
+
+        Matrix {
+            rows: self.len,
+            cols: self.len,
+            data,
+        }
+    }
+}
+
+impl<'a, T: Ring> From<&'a Matrix<T>> for BandedMatrix<T> {
+    /// Convert a square matrix, using the narrowest band holding all of its non-zero cells.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    fn from(mat: &'a Matrix<T>) -> BandedMatrix<T> {
+        assert!(mat.rows == mat.cols);
+
+        let len = mat.rows;
+        let (mut lower, mut upper) = (0, 0);
+        for (i, v) in mat.data.iter().enumerate() {
+            if *v != T::zero() {
+                let (row, col) = (i / len, i % len);
+                lower = lower.max(row.saturating_sub(col));
+                upper = upper.max(col.saturating_sub(row));
+            }
+        }
+
+        let mut banded = BandedMatrix::new(len, lower, upper);
+        for row in 0..len {
+            for col in banded.band(row) {
+                banded.set(row, col, mat.data[col + row * len].clone());
+            }
+        }
+        banded
+    }
+}
+
+impl<T: Ring> LinearOperator<T> for BandedMatrix<T> {
+    fn rows(&self) -> usize {
+        self.len
+    }
+
+    fn cols(&self) -> usize {
+        self.len
+    }
+
+    fn mul_vec(&self, x: &[T], y: &mut [T]) {
+        assert!(x.len() == self.len);
+        assert!(y.len() == self.len);
+
+        for (row, y) in y.iter_mut().enumerate() {
+            *y = self.band(row).fold(T::zero(), |acc, col| {
+                acc + self.get(row, col).unwrap().clone() * x[col].clone()
+            });
+        }
+    }
+}
+
+impl<T: Real> BandedMatrix<T> {
+    /// Compute the LU factorization with partial pivoting of the matrix,
+    /// in `O(N * lower * (lower + upper))` operations.
+    /// Returns `None` if the matrix is singular.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat = BandedMatrix::tridiagonal(&[-1.0; 4], &[2.0; 5], &[-1.0; 4]);
+    /// let lu = mat.lu().unwrap();
+    ///
+    /// assert_eq!(lu.determinant(), 6.0);
+    /// let x = lu.solve(&[1.0; 5]);
+    /// ```
+    pub fn lu(&self) -> Option<BandedLu<T>> {
+        let n = self.len;
+        let kl = self.lower;
+        let ku = kl + self.upper;
+        // Row `i` of the working storage holds the columns `i - kl..=i + ku`,
+        // the extra `kl` superdiagonals receiving the fill-in of the row interchanges
+        let width = kl + ku + 1;
+        let mut work = vec![T::zero(); n * width];
+        for row in 0..n {
+            for col in self.band(row) {
+                work[col + kl - row + row * width] = *self.get(row, col).unwrap();
+            }
+        }
+        let at = |row: usize, col: usize| col + kl - row + row * width;
+
+        let mut l = vec![T::zero(); n * kl];
+        let mut pivots = Vec::with_capacity(n);
+        for k in 0..n {
+            let last_row = (k + kl).min(n - 1);
+            let last_col = (k + ku).min(n - 1);
+
+            let pivot = (k..=last_row)
+                .max_by(|&i, &j| {
+                    work[at(i, k)]
+                        .abs()
+                        .partial_cmp(&work[at(j, k)].abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap();
+            if work[at(pivot, k)] == T::zero() || !work[at(pivot, k)].is_finite() {
+                return None;
+            }
+            if pivot != k {
+                for col in k..=last_col {
+                    work.swap(at(pivot, col), at(k, col));
+                }
+            }
+            pivots.push(pivot);
+
+            let p = work[at(k, k)];
+            for row in k + 1..=last_row {
+                let factor = work[at(row, k)] / p;
+                l[row - k - 1 + k * kl] = factor;
+                for col in k + 1..=last_col {
+                    let v = work[at(k, col)];
+                    work[at(row, col)] -= factor * v;
+                }
+            }
+        }
+
+        // Keep only `U`, which starts at the diagonal of each row
+        let u = (0..n)
+            .flat_map(|row| work[at(row, row)..at(row, row) + ku + 1].iter().cloned())
+            .collect();
+
+        Some(BandedLu {
+            len: n,
+            lower: kl,
+            upper: ku,
+            u,
+            l,
+            pivots,
+        })
+    }
+
+    /// Solve the tridiagonal system `A * x = b` with the Thomas algorithm,
+    /// in `O(N)` operations.
+    /// The algorithm does not pivot, so it is only stable for matrices
+    /// such as diagonally dominant or symmetric positive definite ones;
+    /// use `lu` otherwise.
+    /// Returns `None` if a zero pivot is encountered.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not tridiagonal (`lower` and `upper` must be at most `1`),
+    /// or if the size of `b` does not match.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat = BandedMatrix::tridiagonal(&[-1.0; 2], &[2.0; 3], &[-1.0; 2]);
+    ///
+    /// assert_eq!(mat.solve_tridiagonal(&[1.0, 0.0, 1.0]), Some(vec![1.0, 1.0, 1.0]));
+    /// ```
+    pub fn solve_tridiagonal(&self, b: &[T]) -> Option<Vec<T>> {
+        assert!(self.lower <= 1 && self.upper <= 1);
+        assert!(b.len() == self.len);
+
+        let n = self.len;
+        let cell = |row: usize, col: usize| self.get(row, col).cloned().unwrap_or_else(T::zero);
+
+        // Forward sweep: c[i] and x[i] hold the modified superdiagonal and right-hand side
+        let mut c = Vec::with_capacity(n);
+        let mut x = Vec::with_capacity(n);
+        for i in 0..n {
+            let (m, rhs) = if i == 0 {
+                (cell(0, 0), b[0])
+            } else {
+                let a = cell(i, i - 1);
+                (cell(i, i) - a * c[i - 1], b[i] - a * x[i - 1])
+            };
+            if m == T::zero() || !m.is_finite() {
+                return None;
+            }
+            c.push(if i + 1 < n {
+                cell(i, i + 1) / m
+            } else {
+                T::zero()
+            });
+            x.push(rhs / m);
+        }
+
+        // Back substitution
+        for i in (0..n - 1).rev() {
+            let v = x[i + 1];
+            x[i] -= c[i] * v;
+        }
+
+        Some(x)
+    }
+}
+
+impl<T: Real> BandedLu<T> {
+    /// Returns the determinant of the factorized matrix.
+    pub fn determinant(&self) -> T {
+        let width = self.upper + 1;
+        self.pivots
+            .iter()
+            .enumerate()
+            .fold(T::one(), |acc, (k, &p)| {
+                let d = self.u[k * width];
+                if p == k {
+                    acc * d
+                } else {
+                    -(acc * d)
+                }
+            })
+    }
+
+    /// Solve `A * x = b` for `x`, in `O(N * (lower + upper))` operations.
+    ///
+    /// # Panics
+    /// Panics if the size of `b` does not match.
+    pub fn solve(&self, b: &[T]) -> Vec<T> {
+        assert!(b.len() == self.len);
+
+        let n = self.len;
+        let kl = self.lower;
+        let width = self.upper + 1;
+        let mut x = b.to_vec();
+
+        // Apply the row interchanges and `L`, in the order of the factorization
+        for k in 0..n {
+            x.swap(k, self.pivots[k]);
+            let v = x[k];
+            let below = &mut x[k + 1..(k + kl + 1).min(n)];
+            for (x, &l) in below.iter_mut().zip(&self.l[k * kl..]) {
+                *x -= l * v;
+            }
+        }
+
+        // Back substitution with `U`
+        for row in (0..n).rev() {
+            for col in row + 1..(row + width).min(n) {
+                let v = x[col];
+                x[row] -= self.u[col - row + row * width] * v;
+            }
+            x[row] /= self.u[row * width];
+        }
+
+        x
+    }
+}
@@ -0,0 +1,280 @@
+use super::bytes::LeBytes;
+use super::Matrix;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+/// Magic number starting every file.
+pub(crate) const MAGIC: &[u8; 8] = b"SMATRIX\0";
+/// Current version of the format.
+pub(crate) const VERSION: u16 = 1;
+/// Size of the header, which keeps the data 8-byte aligned.
+pub(crate) const HEADER_LEN: usize = 32;
+
+/// Primitive cell types which can be stored in the crate's binary format:
+/// `bool`, the integer types up to 64 bits, `f32` and `f64`.
+///
+/// This trait is sealed, and cannot be implemented outside of this crate.
+pub trait BinaryElement: LeBytes {}
+
+impl<T: LeBytes> BinaryElement for T {}
+
+/// Error returned by `Matrix::load`.
+#[derive(Debug)]
+pub enum BinaryError {
+    /// The input could not be read, or is truncated.
+    Io(io::Error),
+    /// The input does not start with the magic number of the format.
+    InvalidMagic,
+    /// The input was written by an unsupported version of the format.
+    UnsupportedVersion(u16),
+    /// The cells of the input are not of the requested type.
+    TypeMismatch {
+        /// Tag of the requested type, e.g. `f8` for `f64`.
+        expected: String,
+        /// Tag of the stored type.
+        found: String,
+    },
+    /// The header is corrupted.
+    InvalidHeader(String),
+    /// The checksum of the input does not match its content.
+    ChecksumMismatch {
+        /// Checksum stored in the input.
+        expected: u32,
+        /// Checksum of the content.
+        found: u32,
+    },
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BinaryError::Io(err) => write!(f, "cannot read matrix: {}", err),
+            BinaryError::InvalidMagic => write!(f, "not a simple-matrix file"),
+            BinaryError::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {}", version)
+            }
+            BinaryError::TypeMismatch { expected, found } => {
+                write!(f, "expected cells of type {}, found {}", expected, found)
+            }
+            BinaryError::InvalidHeader(message) => write!(f, "invalid header: {}", message),
+            BinaryError::ChecksumMismatch { expected, found } => write!(
+                f,
+                "checksum mismatch: expected {:08x}, found {:08x}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl Error for BinaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BinaryError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BinaryError {
+    fn from(err: io::Error) -> Self {
+        BinaryError::Io(err)
+    }
+}
+
+/// Decoded header of a file.
+pub(crate) struct Header {
+    pub(crate) little_endian: bool,
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+}
+
+impl Header {
+    /// Encode the header of a little endian `rows`x`cols` matrix of `T`.
+    pub(crate) fn encode<T: BinaryElement>(rows: usize, cols: usize) -> [u8; HEADER_LEN] {
+        let mut header = [0; HEADER_LEN];
+        header[..8].copy_from_slice(MAGIC);
+        header[8..10].copy_from_slice(&VERSION.to_le_bytes());
+        header[10] = T::KIND as u8;
+        header[11] = T::SIZE as u8;
+        header[12] = 0;
+        header[16..24].copy_from_slice(&(rows as u64).to_le_bytes());
+        header[24..32].copy_from_slice(&(cols as u64).to_le_bytes());
+        header
+    }
+
+    /// Validate the header of a file of `T`.
+    pub(crate) fn decode<T: BinaryElement>(
+        header: &[u8; HEADER_LEN],
+    ) -> Result<Header, BinaryError> {
+        if &header[..8] != MAGIC {
+            return Err(BinaryError::InvalidMagic);
+        }
+        let version = u16::from_le_bytes([header[8], header[9]]);
+        if version != VERSION {
+            return Err(BinaryError::UnsupportedVersion(version));
+        }
+        if header[10] != T::KIND as u8 || usize::from(header[11]) != T::SIZE {
+            return Err(BinaryError::TypeMismatch {
+                expected: type_tag(T::KIND as u8, T::SIZE as u8),
+                found: type_tag(header[10], header[11]),
+            });
+        }
+        let little_endian = match header[12] {
+            0 => true,
+            1 => false,
+            other => {
+                return Err(BinaryError::InvalidHeader(format!(
+                    "invalid endianness {}",
+                    other
+                )))
+            }
+        };
+
+        let mut size = [0; 8];
+        size.copy_from_slice(&header[16..24]);
+        let rows = u64::from_le_bytes(size) as usize;
+        size.copy_from_slice(&header[24..32]);
+        let cols = u64::from_le_bytes(size) as usize;
+        let len = rows.checked_mul(cols).and_then(|n| n.checked_mul(T::SIZE));
+        if rows == 0 || cols == 0 || len.is_none() {
+            return Err(BinaryError::InvalidHeader(format!(
+                "invalid matrix size {}x{}",
+                rows, cols
+            )));
+        }
+
+        Ok(Header {
+            little_endian,
+            rows,
+            cols,
+        })
+    }
+
+    /// Size of the data following the header, in bytes.
+    pub(crate) fn data_len<T: BinaryElement>(&self) -> usize {
+        self.rows * self.cols * T::SIZE
+    }
+}
+
+/// Printable tag of a cell type, e.g. `f8` for `f64`.
+fn type_tag(kind: u8, size: u8) -> String {
+    format!("{}{}", char::from(kind), size)
+}
+
+impl<T: BinaryElement> Matrix<T> {
+    /// Write the matrix in the crate's binary format:
+    /// a 32 bytes header (magic number, format version, cell type, endianness and size),
+    /// the cells row by row in little endian, and a CRC-32 of everything before it.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(100, 100, (0..).map(f64::from));
+    /// mat.save(std::io::BufWriter::new(std::fs::File::create("cache.smx")?))?;
+    /// ```
+    pub fn save<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let header = Header::encode::<T>(self.rows, self.cols);
+        let mut crc = Crc32::new();
+        crc.update(&header);
+        writer.write_all(&header)?;
+
+        let mut buf = Vec::with_capacity(4096);
+        for chunk in self.data.chunks(4096 / T::SIZE) {
+            buf.clear();
+            for &v in chunk {
+                v.write_le(&mut buf);
+            }
+            crc.update(&buf);
+            writer.write_all(&buf)?;
+        }
+
+        writer.write_all(&crc.finish().to_le_bytes())?;
+        writer.flush()
+    }
+
+    /// Read a matrix written by `Matrix::save`.
+    ///
+    /// # Errors
+    /// Returns a `BinaryError` if the input is not in the crate's binary format,
+    /// is truncated or corrupted, or does not hold cells of type `T`.
+    ///
+    /// # Examples
+    /// ```
+    /// let file = std::fs::File::open("cache.smx")?;
+    /// let mat: Matrix<f64> = Matrix::load(std::io::BufReader::new(file))?;
+    /// ```
+    pub fn load<R: Read>(mut reader: R) -> Result<Matrix<T>, BinaryError> {
+        let mut header = [0; HEADER_LEN];
+        reader.read_exact(&mut header)?;
+        let decoded = Header::decode::<T>(&header)?;
+
+        // Read through `take` so that a corrupted size fails on EOF instead of allocating
+        let len = decoded.data_len::<T>();
+        let mut bytes = Vec::new();
+        (&mut reader).take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(
+                io::Error::new(io::ErrorKind::UnexpectedEof, "truncated matrix data").into(),
+            );
+        }
+
+        let mut checksum = [0; 4];
+        reader.read_exact(&mut checksum)?;
+        let expected = u32::from_le_bytes(checksum);
+        let mut crc = Crc32::new();
+        crc.update(&header);
+        crc.update(&bytes);
+        let found = crc.finish();
+        if found != expected {
+            return Err(BinaryError::ChecksumMismatch { expected, found });
+        }
+
+        Ok(Matrix {
+            rows: decoded.rows,
+            cols: decoded.cols,
+            data: bytes
+                .chunks(T::SIZE)
+                .map(|b| T::from_bytes(b, decoded.little_endian))
+                .collect(),
+        })
+    }
+}
+
+/// CRC-32 (IEEE 802.3), as used by zlib and PNG.
+pub(crate) struct Crc32(u32);
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+};
+
+impl Crc32 {
+    pub(crate) fn new() -> Crc32 {
+        Crc32(0xffff_ffff)
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = CRC_TABLE[((self.0 ^ u32::from(b)) & 0xff) as usize] ^ (self.0 >> 8);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u32 {
+        self.0 ^ 0xffff_ffff
+    }
+}
@@ -0,0 +1,56 @@
+/// Fixed size encoding of the primitive cell types, shared by the `.npy` and binary formats.
+/// It bounds the public element traits of both formats, which are thus sealed:
+/// this module is private, so the trait cannot be named outside of the crate.
+pub trait LeBytes: Copy {
+    /// Kind of the type: `b` for booleans, `i` and `u` for integers, `f` for floats.
+    const KIND: char;
+    /// Size of a value, in bytes.
+    const SIZE: usize;
+
+    /// Decode a value from `SIZE` bytes.
+    fn from_bytes(bytes: &[u8], little_endian: bool) -> Self;
+    /// Append the little endian bytes of the value to `out`.
+    fn write_le(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_le_bytes {
+    ($($t:ident: $kind:expr),*) => {
+        $(
+            impl LeBytes for $t {
+                const KIND: char = $kind;
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn from_bytes(bytes: &[u8], little_endian: bool) -> Self {
+                    let mut buf = [0; std::mem::size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    if little_endian {
+                        $t::from_le_bytes(buf)
+                    } else {
+                        $t::from_be_bytes(buf)
+                    }
+                }
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_le_bytes!(i8: 'i', i16: 'i', i32: 'i', i64: 'i');
+impl_le_bytes!(u8: 'u', u16: 'u', u32: 'u', u64: 'u');
+impl_le_bytes!(f32: 'f', f64: 'f');
+
+impl LeBytes for bool {
+    const KIND: char = 'b';
+    const SIZE: usize = 1;
+
+    fn from_bytes(bytes: &[u8], _: bool) -> Self {
+        bytes[0] != 0
+    }
+
+    fn write_le(self, out: &mut Vec<u8>) {
+        out.push(self as u8);
+    }
+}
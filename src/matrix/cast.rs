@@ -0,0 +1,170 @@
+use super::Matrix;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// Error returned by `Matrix::try_cast` for the first cell which cannot be converted.
+///
+/// Rows and columns are counted from `0`, as with `Matrix::get`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CastError<E> {
+    /// Row of the cell.
+    pub row: usize,
+    /// Column of the cell.
+    pub col: usize,
+    /// Error of the conversion of the cell.
+    pub error: E,
+}
+
+impl<E: Display> Display for CastError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cannot convert cell ({}, {}): {}",
+            self.row, self.col, self.error
+        )
+    }
+}
+
+impl<E: Error + 'static> Error for CastError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Conversions from floating point numbers to integers, used by `Matrix::saturating_cast`
+/// and `Matrix::round_cast`.
+///
+/// Values out of the range of the integer are clamped to its bounds, and `NaN` becomes `0`.
+pub trait FloatCast<I>: Copy {
+    /// Converts `self`, rounded toward zero.
+    fn saturating_cast(self) -> I;
+    /// Converts `self`, rounded to the nearest integer (half way cases away from zero).
+    fn round_cast(self) -> I;
+}
+
+macro_rules! impl_float_cast {
+    ($float:ident => $($int:ident),*) => {
+        $(
+            impl FloatCast<$int> for $float {
+                // `as` saturates since Rust 1.45
+                fn saturating_cast(self) -> $int { self as $int }
+                fn round_cast(self) -> $int { self.round() as $int }
+            }
+        )*
+    };
+}
+
+impl_float_cast!(f32 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_float_cast!(f64 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T> Matrix<T> {
+    /// Consume the matrix, applying a function to each cell.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    /// let labels: Matrix<String> = mat.map(|n| format!("#{}", n));
+    ///
+    /// assert_eq!(labels.get(1, 0).unwrap(), "#3");
+    /// ```
+    pub fn map<U, F: FnMut(T) -> U>(self, func: F) -> Matrix<U> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().map(func).collect(),
+        }
+    }
+
+    /// Build a new matrix by applying a function to a reference to each cell.
+    ///
+    /// # Examples
+    /// ```
+    /// let names: Matrix<&str> = Matrix::from_iter(1, 2, vec!["a", "bcd"]);
+    /// let lengths: Matrix<usize> = names.map_ref(|s| s.len());
+    ///
+    /// assert_eq!(lengths, Matrix::from_iter(1, 2, vec![1, 3]));
+    /// ```
+    pub fn map_ref<U, F: FnMut(&T) -> U>(&self, func: F) -> Matrix<U> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(func).collect(),
+        }
+    }
+
+    /// Convert each cell with `From`, including for user types.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<u8> = Matrix::from_iter(2, 2, 1..);
+    /// let wide: Matrix<f64> = mat.cast();
+    /// ```
+    pub fn cast<U: From<T>>(self) -> Matrix<U> {
+        self.map(U::from)
+    }
+
+    /// Convert each cell with `TryFrom`, e.g. for narrowing conversions.
+    /// Fails with the position of the first cell (row by row) which cannot be converted.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(1, 3, vec![1, -2, 300]);
+    /// let err = mat.try_cast::<u8>().unwrap_err();
+    ///
+    /// assert_eq!((err.row, err.col), (0, 1));
+    /// ```
+    pub fn try_cast<U: TryFrom<T>>(self) -> Result<Matrix<U>, CastError<U::Error>> {
+        let cols = self.cols;
+        let data = self
+            .data
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| {
+                U::try_from(v).map_err(|error| CastError {
+                    row: i / cols,
+                    col: i % cols,
+                    error,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols,
+            data,
+        })
+    }
+
+    /// Convert floating point cells to integers, rounded toward zero.
+    /// Values out of range are clamped to the bounds of the integer type, and `NaN` becomes `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(1, 4, vec![-1.7, 2.9, 300.0, f64::NAN]);
+    ///
+    /// assert_eq!(mat.saturating_cast::<u8>(), Matrix::from_iter(1, 4, vec![0, 2, 255, 0]));
+    /// ```
+    pub fn saturating_cast<U>(&self) -> Matrix<U>
+    where
+        T: FloatCast<U>,
+    {
+        self.map_ref(|v| v.saturating_cast())
+    }
+
+    /// Convert floating point cells to the nearest integers, half way cases away from zero.
+    /// Values out of range are clamped to the bounds of the integer type, and `NaN` becomes `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(1, 4, vec![-1.7, 2.5, 300.0, f64::NAN]);
+    ///
+    /// assert_eq!(mat.round_cast::<i8>(), Matrix::from_iter(1, 4, vec![-2, 3, 127, 0]));
+    /// ```
+    pub fn round_cast<U>(&self) -> Matrix<U>
+    where
+        T: FloatCast<U>,
+    {
+        self.map_ref(|v| v.round_cast())
+    }
+}
@@ -0,0 +1,454 @@
+use super::Matrix;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+/// How `Matrix::read_csv` handles empty cells, and cells equal to one of the missing tokens.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MissingValues<T> {
+    /// Fail with `CsvError::MissingValue`.
+    Error,
+    /// Replace the missing cell with the given value (e.g. `f64::NAN` or `0`).
+    Fill(T),
+}
+
+/// Options of `Matrix::read_csv`.
+#[derive(Clone, Debug)]
+pub struct CsvReadOptions<T> {
+    /// Character separating the cells of a row.
+    pub delimiter: char,
+    /// Character enclosing cells which contain delimiters, quotes or line breaks.
+    /// Quotes are escaped inside such cells by doubling them.
+    pub quote: char,
+    /// Number of header records to skip before the values.
+    pub header_rows: usize,
+    /// Whether to remove the whitespace around unquoted cells before parsing them.
+    /// Quoted cells are always kept as is.
+    pub trim: bool,
+    /// Cells considered missing, in addition to the empty ones (e.g. `"NA"`).
+    pub missing_tokens: Vec<String>,
+    /// What to do with missing cells.
+    pub missing: MissingValues<T>,
+}
+
+impl<T> Default for CsvReadOptions<T> {
+    /// Comma delimited, double quoted, no header, trimmed cells,
+    /// and missing values are errors.
+    fn default() -> Self {
+        CsvReadOptions {
+            delimiter: ',',
+            quote: '"',
+            header_rows: 0,
+            trim: true,
+            missing_tokens: Vec::new(),
+            missing: MissingValues::Error,
+        }
+    }
+}
+
+/// Options of `Matrix::write_csv`.
+#[derive(Clone, Debug)]
+pub struct CsvWriteOptions {
+    /// Character separating the cells of a row.
+    pub delimiter: char,
+    /// Character enclosing cells which need to be quoted.
+    pub quote: char,
+    /// Names of the columns, written as a header record.
+    pub header: Option<Vec<String>>,
+    /// Whether to quote every cell, instead of only the ones which need it.
+    pub quote_all: bool,
+}
+
+impl Default for CsvWriteOptions {
+    /// Comma delimited, double quoted, and no header.
+    fn default() -> Self {
+        CsvWriteOptions {
+            delimiter: ',',
+            quote: '"',
+            header: None,
+            quote_all: false,
+        }
+    }
+}
+
+/// Error returned by `Matrix::read_csv`.
+///
+/// Lines and columns are counted from `1`:
+/// `line` is the line of the input where the cell starts,
+/// and `column` is the position of the cell in its record.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The input could not be read, or is not valid UTF-8.
+    Io(io::Error),
+    /// A quoted cell is not closed before the end of the input.
+    UnterminatedQuote {
+        /// Line where the cell starts.
+        line: usize,
+        /// Position of the cell in its record.
+        column: usize,
+    },
+    /// A quoted cell is followed by something else than a delimiter or a line break.
+    UnexpectedCharacter {
+        /// Line of the unexpected character.
+        line: usize,
+        /// Position of the cell in its record.
+        column: usize,
+        /// The unexpected character.
+        found: char,
+    },
+    /// A record does not have as many cells as the first one.
+    RaggedRow {
+        /// Line where the record starts.
+        line: usize,
+        /// Number of cells of the first record.
+        expected: usize,
+        /// Number of cells of this record.
+        found: usize,
+    },
+    /// A cell is missing, and `MissingValues::Error` was requested.
+    MissingValue {
+        /// Line where the cell starts.
+        line: usize,
+        /// Position of the cell in its record.
+        column: usize,
+    },
+    /// A cell could not be parsed.
+    InvalidValue {
+        /// Line where the cell starts.
+        line: usize,
+        /// Position of the cell in its record.
+        column: usize,
+        /// Content of the cell.
+        cell: String,
+        /// Message of the parsing error.
+        message: String,
+    },
+    /// The input does not contain any value.
+    Empty,
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "cannot read CSV: {}", err),
+            CsvError::UnterminatedQuote { line, column } => {
+                write!(
+                    f,
+                    "line {}, column {}: unterminated quoted cell",
+                    line, column
+                )
+            }
+            CsvError::UnexpectedCharacter {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {}, column {}: unexpected {:?} after a quoted cell",
+                line, column, found
+            ),
+            CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} cells, found {}",
+                line, expected, found
+            ),
+            CsvError::MissingValue { line, column } => {
+                write!(f, "line {}, column {}: missing value", line, column)
+            }
+            CsvError::InvalidValue {
+                line,
+                column,
+                cell,
+                message,
+            } => write!(
+                f,
+                "line {}, column {}: cannot parse {:?}: {}",
+                line, column, cell, message
+            ),
+            CsvError::Empty => write!(f, "CSV does not contain any value"),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(err: io::Error) -> Self {
+        CsvError::Io(err)
+    }
+}
+
+impl<T: FromStr + Clone> Matrix<T>
+where
+    T::Err: Display,
+{
+    /// Read a matrix from CSV data, one record per row.
+    /// Blank lines are ignored, and every record must have the same number of cells.
+    ///
+    /// # Errors
+    /// Returns a `CsvError` locating the faulty cell
+    /// if the input is malformed, or if a cell is missing or cannot be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// let data = "x;y\n1.5;2\n;4\n";
+    /// let mat: Matrix<f64> = Matrix::read_csv(data.as_bytes(), &CsvReadOptions {
+    ///     delimiter: ';',
+    ///     header_rows: 1,
+    ///     missing: MissingValues::Fill(0.0),
+    ///     ..CsvReadOptions::default()
+    /// }).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 2, vec![1.5, 2.0, 0.0, 4.0]));
+    /// ```
+    pub fn read_csv<R: Read>(
+        mut reader: R,
+        options: &CsvReadOptions<T>,
+    ) -> Result<Matrix<T>, CsvError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+
+        let mut cols = 0;
+        let mut rows = 0;
+        let mut data = Vec::new();
+        for (line, record) in parse(&input, options.delimiter, options.quote)?
+            .into_iter()
+            .skip(options.header_rows)
+        {
+            if rows == 0 {
+                cols = record.len();
+            } else if record.len() != cols {
+                return Err(CsvError::RaggedRow {
+                    line,
+                    expected: cols,
+                    found: record.len(),
+                });
+            }
+
+            for (column, cell) in (1..).zip(record) {
+                let text = if options.trim && !cell.quoted {
+                    cell.text.trim()
+                } else {
+                    &cell.text[..]
+                };
+
+                if text.is_empty() || options.missing_tokens.iter().any(|t| t == text) {
+                    match &options.missing {
+                        MissingValues::Error => {
+                            return Err(CsvError::MissingValue {
+                                line: cell.line,
+                                column,
+                            })
+                        }
+                        MissingValues::Fill(v) => data.push(v.clone()),
+                    }
+                } else {
+                    let v = text.parse().map_err(|err: T::Err| CsvError::InvalidValue {
+                        line: cell.line,
+                        column,
+                        cell: text.to_string(),
+                        message: err.to_string(),
+                    })?;
+                    data.push(v);
+                }
+            }
+            rows += 1;
+        }
+
+        if rows == 0 || cols == 0 {
+            return Err(CsvError::Empty);
+        }
+
+        Ok(Matrix { rows, cols, data })
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    /// Write the matrix as CSV data, one record per row.
+    /// Cells are quoted when they contain a delimiter, a quote, a line break,
+    /// or surrounding whitespace, so that `read_csv` reads them back unchanged.
+    /// Empty cells are the exception: `read_csv` considers them missing, quoted or not,
+    /// so they are only read back with `MissingValues::Fill(String::new())`.
+    /// They are quoted for single column matrices, as their lines would be skipped otherwise.
+    ///
+    /// # Panics
+    /// Panics if a header is given without one name per column.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 2, 0..);
+    /// let mut out = Vec::new();
+    /// mat.write_csv(&mut out, &CsvWriteOptions {
+    ///     header: Some(vec!["a".to_string(), "b, c".to_string()]),
+    ///     ..CsvWriteOptions::default()
+    /// }).unwrap();
+    ///
+    /// assert_eq!(out, b"a,\"b, c\"\n0,1\n2,3\n");
+    /// ```
+    pub fn write_csv<W: Write>(&self, mut writer: W, options: &CsvWriteOptions) -> io::Result<()> {
+        let mut line = String::new();
+
+        if let Some(header) = &options.header {
+            assert!(header.len() == self.cols);
+            write_record(&mut line, header, options);
+            writer.write_all(line.as_bytes())?;
+        }
+
+        for row in self.data.chunks(self.cols) {
+            let cells: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+            write_record(&mut line, &cells, options);
+            writer.write_all(line.as_bytes())?;
+        }
+
+        writer.flush()
+    }
+}
+
+/// A raw cell, along with the line where it starts.
+struct Cell {
+    text: String,
+    line: usize,
+    quoted: bool,
+}
+
+/// Split CSV data into records, along with the line where they start.
+/// Blank lines are skipped.
+fn parse(input: &str, delimiter: char, quote: char) -> Result<Vec<(usize, Vec<Cell>)>, CsvError> {
+    let mut records = Vec::new();
+    let mut chars = input.chars().peekable();
+    let mut line = 1;
+
+    while chars.peek().is_some() {
+        let start = line;
+        let mut record: Vec<Cell> = Vec::new();
+        let mut blank = true;
+
+        loop {
+            let mut cell = Cell {
+                text: String::new(),
+                line,
+                quoted: chars.peek() == Some(&quote),
+            };
+            let column = record.len() + 1;
+
+            if cell.quoted {
+                chars.next();
+                blank = false;
+                loop {
+                    match chars.next() {
+                        None => {
+                            return Err(CsvError::UnterminatedQuote {
+                                line: cell.line,
+                                column,
+                            })
+                        }
+                        Some(c) if c == quote => {
+                            if chars.peek() == Some(&quote) {
+                                chars.next();
+                                cell.text.push(quote);
+                            } else {
+                                break;
+                            }
+                        }
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            cell.text.push(c);
+                        }
+                    }
+                }
+
+                // Only whitespace may follow the closing quote
+                while let Some(&c) = chars.peek() {
+                    if c == delimiter || c == '\n' || c == '\r' {
+                        break;
+                    } else if c == ' ' || c == '\t' {
+                        chars.next();
+                    } else {
+                        return Err(CsvError::UnexpectedCharacter {
+                            line,
+                            column,
+                            found: c,
+                        });
+                    }
+                }
+            } else {
+                while let Some(&c) = chars.peek() {
+                    if c == delimiter || c == '\n' || c == '\r' {
+                        break;
+                    }
+                    cell.text.push(c);
+                    chars.next();
+                }
+                blank &= cell.text.trim().is_empty();
+            }
+            record.push(cell);
+
+            match chars.next() {
+                Some(c) if c == delimiter => blank = false,
+                Some('\r') => {
+                    if chars.peek() == Some(&'\n') {
+                        chars.next();
+                    }
+                    line += 1;
+                    break;
+                }
+                Some('\n') => {
+                    line += 1;
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        if !blank {
+            records.push((start, record));
+        }
+    }
+
+    Ok(records)
+}
+
+/// Format a record into `line`, quoting its cells as needed.
+fn write_record(line: &mut String, cells: &[String], options: &CsvWriteOptions) {
+    line.clear();
+    for (i, cell) in cells.iter().enumerate() {
+        if i > 0 {
+            line.push(options.delimiter);
+        }
+
+        // An empty cell alone on its line would be read back as a blank line
+        let needs_quotes = options.quote_all
+            || (cells.len() == 1 && cell.is_empty())
+            || cell.trim() != cell
+            || cell
+                .chars()
+                .any(|c| c == options.delimiter || c == options.quote || c == '\n' || c == '\r');
+        if needs_quotes {
+            line.push(options.quote);
+            for c in cell.chars() {
+                if c == options.quote {
+                    line.push(c);
+                }
+                line.push(c);
+            }
+            line.push(options.quote);
+        } else {
+            line.push_str(cell);
+        }
+    }
+    line.push('\n');
+}
@@ -0,0 +1,162 @@
+//! Dense building blocks shared by the numerical algorithms of the crate.
+
+use super::{Matrix, Real, Ring};
+
+/// Construct the *N*x*N* identity matrix.
+pub(crate) fn identity<T: Ring>(len: usize) -> Matrix<T> {
+    Matrix::from_iter(
+        len,
+        len,
+        (0..len * len).map(|i| {
+            if i % (len + 1) == 0 {
+                T::one()
+            } else {
+                T::zero()
+            }
+        }),
+    )
+}
+
+/// Construct the product of two matrices, without consuming them.
+pub(crate) fn matmul<T: Ring>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    assert!(a.cols == b.rows);
+
+    let mut data = Vec::with_capacity(a.rows * b.cols);
+    for row in 0..a.rows {
+        for col in 0..b.cols {
+            let mut acc = T::zero();
+            for k in 0..a.cols {
+                acc = acc + a.data[k + row * a.cols].clone() * b.data[col + k * b.cols].clone();
+            }
+            data.push(acc);
+        }
+    }
+
+    Matrix {
+        rows: a.rows,
+        cols: b.cols,
+        data,
+    }
+}
+
+/// LU factorization with partial pivoting of a square matrix: `P * A = L * U`.
+pub(crate) struct Lu<T> {
+    len: usize,
+    /// `L` (unit diagonal omitted) and `U` packed together, row-major.
+    lu: Vec<T>,
+    /// Row `i` of `P * A` is row `perm[i]` of `A`.
+    perm: Vec<usize>,
+    swaps: usize,
+}
+
+impl<T: Real> Lu<T> {
+    /// Factorize a square matrix.
+    /// Returns `None` if the matrix is exactly singular.
+    pub(crate) fn new(a: &Matrix<T>) -> Option<Lu<T>> {
+        assert!(a.rows == a.cols);
+
+        let n = a.rows;
+        let mut lu = a.data.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| {
+                    lu[k + i * n]
+                        .abs()
+                        .partial_cmp(&lu[k + j * n].abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap();
+            if lu[k + pivot * n] == T::zero() || !lu[k + pivot * n].is_finite() {
+                return None;
+            }
+            if pivot != k {
+                for c in 0..n {
+                    lu.swap(c + pivot * n, c + k * n);
+                }
+                perm.swap(pivot, k);
+                swaps += 1;
+            }
+
+            let p = lu[k + k * n];
+            for i in k + 1..n {
+                let factor = lu[k + i * n] / p;
+                lu[k + i * n] = factor;
+                for j in k + 1..n {
+                    let v = lu[j + k * n];
+                    lu[j + i * n] -= factor * v;
+                }
+            }
+        }
+
+        Some(Lu {
+            len: n,
+            lu,
+            perm,
+            swaps,
+        })
+    }
+
+    /// Returns the determinant of the factorized matrix.
+    pub(crate) fn determinant(&self) -> T {
+        let n = self.len;
+        let det = (0..n).fold(T::one(), |acc, i| acc * self.lu[i + i * n]);
+        if self.swaps & 1 == 0 {
+            det
+        } else {
+            -det
+        }
+    }
+
+    /// Solve `A * X = B` for `X`.
+    pub(crate) fn solve(&self, b: &Matrix<T>) -> Matrix<T> {
+        assert!(b.rows == self.len);
+
+        let n = self.len;
+        let cols = b.cols;
+        let mut x: Vec<T> = self
+            .perm
+            .iter()
+            .flat_map(|&row| b.data[row * cols..(row + 1) * cols].iter().cloned())
+            .collect();
+
+        // Forward substitution with L
+        for i in 0..n {
+            for k in 0..i {
+                let l = self.lu[k + i * n];
+                for c in 0..cols {
+                    let v = x[c + k * cols];
+                    x[c + i * cols] -= l * v;
+                }
+            }
+        }
+
+        // Back substitution with U
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                let u = self.lu[k + i * n];
+                for c in 0..cols {
+                    let v = x[c + k * cols];
+                    x[c + i * cols] -= u * v;
+                }
+            }
+            let d = self.lu[i + i * n];
+            for c in 0..cols {
+                x[c + i * cols] /= d;
+            }
+        }
+
+        Matrix {
+            rows: n,
+            cols,
+            data: x,
+        }
+    }
+
+    /// Construct the inverse of the factorized matrix.
+    pub(crate) fn inverse(&self) -> Matrix<T> {
+        self.solve(&identity(self.len))
+    }
+}
@@ -0,0 +1,193 @@
+use super::Matrix;
+use std::fmt::{self, Alignment, Display, Write};
+
+/// Delimiters drawn around the rows by `Display`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplayStyle {
+    /// Square brackets around each row: `[ 1  2 ]`.
+    Brackets,
+    /// Box-drawing characters around the whole matrix.
+    Box,
+    /// No delimiters, only the aligned cells.
+    Plain,
+}
+
+/// Options of `Matrix::format_with`.
+///
+/// The width, fill, alignment and precision of the format specifier
+/// (e.g. `{:>8.3}`) apply to each cell.
+#[derive(Clone, Debug)]
+pub struct DisplayOptions {
+    /// Delimiters drawn around the rows.
+    pub style: DisplayStyle,
+    /// Number of decimals of the cells, overriding the precision of the format specifier.
+    pub precision: Option<usize>,
+    /// Rows displayed before eliding the middle ones.
+    pub max_rows: usize,
+    /// Columns displayed before eliding the middle ones.
+    pub max_cols: usize,
+    /// Rows and columns displayed on each side of an elision.
+    pub edge_items: usize,
+}
+
+impl Default for DisplayOptions {
+    /// Brackets, and elision of the middle of matrices with more than 20 rows or columns,
+    /// keeping 3 rows and columns on each side like NumPy.
+    fn default() -> Self {
+        DisplayOptions {
+            style: DisplayStyle::Brackets,
+            precision: None,
+            max_rows: 20,
+            max_cols: 20,
+            edge_items: 3,
+        }
+    }
+}
+
+/// A matrix along with display options, returned by `Matrix::format_with`.
+pub struct MatrixDisplay<'a, T> {
+    mat: &'a Matrix<T>,
+    options: DisplayOptions,
+}
+
+impl<T: Display> Matrix<T> {
+    /// Display the matrix with the given options.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat = Matrix::from_iter(2, 2, vec![1.0, -2.5, 30.0, 4.125]);
+    /// let text = mat.format_with(DisplayOptions {
+    ///     style: DisplayStyle::Plain,
+    ///     precision: Some(1),
+    ///     ..DisplayOptions::default()
+    /// }).to_string();
+    ///
+    /// assert_eq!(text, " 1.0  -2.5\n30.0   4.1");
+    /// ```
+    pub fn format_with(&self, options: DisplayOptions) -> MatrixDisplay<'_, T> {
+        MatrixDisplay { mat: self, options }
+    }
+}
+
+/// Displays the matrix as an aligned grid, one row per line, with the default `DisplayOptions`.
+///
+/// # Examples
+/// ```
+/// let mat: Matrix<i32> = Matrix::from_iter(2, 3, vec![1, -20, 3, 400, 5, 6]);
+///
+/// assert_eq!(mat.to_string(), "[   1  -20  3 ]\n[ 400    5  6 ]");
+/// assert_eq!(format!("{:<4}", mat), "[ 1     -20   3    ]\n[ 400   5     6    ]");
+/// ```
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format_with(DisplayOptions::default()).fmt(f)
+    }
+}
+
+impl<'a, T: Display> Display for MatrixDisplay<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mat = self.mat;
+        let options = &self.options;
+        let precision = options.precision.or_else(|| f.precision());
+
+        // `None` stands for the elided rows and columns
+        let rows = shown(mat.rows, options.max_rows, options.edge_items);
+        let cols = shown(mat.cols, options.max_cols, options.edge_items);
+
+        let grid: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                cols.iter()
+                    .map(|col| match (row, col) {
+                        (Some(row), Some(col)) => {
+                            let v = &mat.data[col + row * mat.cols];
+                            match precision {
+                                Some(p) => format!("{:.*}", p, v),
+                                None => v.to_string(),
+                            }
+                        }
+                        (Some(_), None) => "…".to_string(),
+                        (None, Some(_)) => "⋮".to_string(),
+                        (None, None) => "⋱".to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let min_width = f.width().unwrap_or(0);
+        let widths: Vec<usize> = (0..cols.len())
+            .map(|col| {
+                grid.iter()
+                    .map(|row| row[col].chars().count())
+                    .fold(min_width, usize::max)
+            })
+            .collect();
+        let align = f.align().unwrap_or(Alignment::Right);
+        let fill = f.fill();
+
+        let mut lines = Vec::with_capacity(grid.len());
+        for row in &grid {
+            let mut line = String::new();
+            for (col, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+                if col > 0 {
+                    line.push_str("  ");
+                }
+                pad(&mut line, cell, width, align, fill)?;
+            }
+            lines.push(line);
+        }
+
+        let inner = lines.first().map_or(0, |l| l.chars().count());
+        match options.style {
+            DisplayStyle::Brackets => {
+                for (i, line) in lines.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char('\n')?;
+                    }
+                    write!(f, "[ {} ]", line)?;
+                }
+                Ok(())
+            }
+            DisplayStyle::Box => {
+                let blank = " ".repeat(inner + 2);
+                write!(f, "┌{}┐", blank)?;
+                for line in &lines {
+                    write!(f, "\n│ {} │", line)?;
+                }
+                write!(f, "\n└{}┘", blank)
+            }
+            DisplayStyle::Plain => f.write_str(&lines.join("\n")),
+        }
+    }
+}
+
+/// Indices of the rows or columns displayed, `None` standing for the elided ones.
+fn shown(len: usize, max: usize, edge_items: usize) -> Vec<Option<usize>> {
+    if len <= max || 2 * edge_items >= len {
+        (0..len).map(Some).collect()
+    } else {
+        (0..edge_items)
+            .map(Some)
+            .chain(std::iter::once(None))
+            .chain((len - edge_items..len).map(Some))
+            .collect()
+    }
+}
+
+/// Append `cell` to `line`, aligned in `width` characters.
+fn pad(line: &mut String, cell: &str, width: usize, align: Alignment, fill: char) -> fmt::Result {
+    let padding = width - cell.chars().count();
+    let (before, after) = match align {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    for _ in 0..before {
+        line.write_char(fill)?;
+    }
+    line.push_str(cell);
+    for _ in 0..after {
+        line.write_char(fill)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use super::{Field, Matrix, Ring};
+
+/// Integer cell types supporting the checked arithmetic
+/// needed by the fraction-free algorithms.
+trait CheckedInt: Ring + Copy {
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_exact {
+    ($($t:ident),*) => {
+        $(
+            impl CheckedInt for $t {
+                fn checked_sub(self, rhs: Self) -> Option<Self> { $t::checked_sub(self, rhs) }
+                fn checked_mul(self, rhs: Self) -> Option<Self> { $t::checked_mul(self, rhs) }
+                fn checked_div(self, rhs: Self) -> Option<Self> { $t::checked_div(self, rhs) }
+            }
+
+            impl Matrix<$t> {
+                /// Compute the exact determinant of a square integer matrix,
+                /// using the fraction-free Bareiss elimination.
+                /// Returns `None` if an intermediate computation overflows.
+                ///
+                /// # Panics
+                /// Panics if the matrix is not square.
+                ///
+                /// # Examples
+                /// ```
+                /// let mat: Matrix<i64> = Matrix::from_iter(2, 2, vec![2, 1, 7, 4]);
+                ///
+                /// assert_eq!(mat.determinant(), Some(1));
+                /// ```
+                pub fn determinant(&self) -> Option<$t> {
+                    assert!(self.rows == self.cols);
+
+                    let mut mat = self.clone();
+                    let (rank, swaps) = bareiss(&mut mat)?;
+
+                    if rank < self.rows {
+                        Some(0)
+                    } else if swaps % 2 == 0 {
+                        Some(mat.data[mat.data.len() - 1])
+                    } else {
+                        mat.data[mat.data.len() - 1].checked_neg()
+                    }
+                }
+
+                /// Returns the exact rank of an integer matrix,
+                /// using the fraction-free Bareiss elimination.
+                /// Returns `None` if an intermediate computation overflows.
+                ///
+                /// # Examples
+                /// ```
+                /// let mat: Matrix<i64> = Matrix::from_iter(3, 3, 0..);
+                ///
+                /// assert_eq!(mat.rank(), Some(2));
+                /// ```
+                pub fn rank(&self) -> Option<usize> {
+                    let mut mat = self.clone();
+                    bareiss(&mut mat).map(|(rank, _)| rank)
+                }
+            }
+        )*
+    };
+}
+
+impl_exact!(i8, i16, i32, i64, i128, isize);
+
+/// Reduce the matrix to a row echelon form in place, without fractions.
+/// Each division performed is exact, as every intermediate value
+/// is a minor of the original matrix.
+/// Returns the rank and the number of row swaps,
+/// or `None` if an intermediate computation overflows.
+fn bareiss<T: CheckedInt>(mat: &mut Matrix<T>) -> Option<(usize, usize)> {
+    let cols = mat.cols;
+    let mut rank = 0;
+    let mut swaps = 0;
+    let mut prev = T::one();
+
+    for col in 0..cols {
+        if rank == mat.rows {
+            break;
+        }
+
+        // Find a non-zero pivot in the current column
+        let pivot = match (rank..mat.rows).find(|&row| mat.data[col + row * cols] != T::zero()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        if pivot != rank {
+            for c in 0..cols {
+                mat.data.swap(c + pivot * cols, c + rank * cols);
+            }
+            swaps += 1;
+        }
+
+        let p = mat.data[col + rank * cols];
+        for row in rank + 1..mat.rows {
+            let lead = mat.data[col + row * cols];
+            for c in col + 1..cols {
+                let a = p.checked_mul(mat.data[c + row * cols])?;
+                let b = lead.checked_mul(mat.data[c + rank * cols])?;
+                mat.data[c + row * cols] = a.checked_sub(b)?.checked_div(prev)?;
+            }
+            mat.data[col + row * cols] = T::zero();
+        }
+
+        prev = p;
+        rank += 1;
+    }
+
+    Some((rank, swaps))
+}
+
+impl<T: Field> Matrix<T> {
+    /// Construct the reduced row echelon form of the matrix,
+    /// using exact Gauss-Jordan elimination over a field.
+    ///
+    /// # Examples
+    /// ```
+    /// // With `Rational` a user-defined type implementing `Field`
+    /// let mat: Matrix<Rational> = Matrix::from_iter(2, 2, (1..).map(Rational::from));
+    ///
+    /// assert_eq!(mat.rref(), Matrix::from_iter(2, 2, vec![1, 0, 0, 1].into_iter().map(Rational::from)));
+    /// ```
+    pub fn rref(&self) -> Matrix<T> {
+        let mut mat = self.clone();
+        let cols = mat.cols;
+        let mut rank = 0;
+
+        for col in 0..cols {
+            if rank == mat.rows {
+                break;
+            }
+
+            let pivot = match (rank..mat.rows).find(|&row| mat.data[col + row * cols] != T::zero())
+            {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            for c in 0..cols {
+                mat.data.swap(c + pivot * cols, c + rank * cols);
+            }
+
+            // Normalize the pivot row
+            let p = mat.data[col + rank * cols].clone();
+            for c in col..cols {
+                let v = mat.data[c + rank * cols].clone();
+                mat.data[c + rank * cols] = v / p.clone();
+            }
+
+            // Eliminate the pivot column from all other rows
+            for row in (0..mat.rows).filter(|&row| row != rank) {
+                let factor = mat.data[col + row * cols].clone();
+                if factor == T::zero() {
+                    continue;
+                }
+                for c in col..cols {
+                    let v = mat.data[c + row * cols].clone();
+                    let r = mat.data[c + rank * cols].clone();
+                    mat.data[c + row * cols] = v - factor.clone() * r;
+                }
+            }
+
+            rank += 1;
+        }
+
+        mat
+    }
+}
@@ -0,0 +1,369 @@
+use super::dense::{identity, matmul, Lu};
+use super::{Matrix, Norm, Real};
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by the matrix functions `Matrix::sqrtm` and `Matrix::logm`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MatrixFunctionError {
+    /// The matrix has no real principal square root,
+    /// i.e. it has eigenvalues on the closed negative real axis.
+    NoRealSquareRoot,
+    /// The matrix has no real principal logarithm,
+    /// i.e. it has eigenvalues on the closed negative real axis.
+    NoRealLogarithm,
+}
+
+impl fmt::Display for MatrixFunctionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixFunctionError::NoRealSquareRoot => {
+                write!(f, "matrix has no real principal square root")
+            }
+            MatrixFunctionError::NoRealLogarithm => {
+                write!(f, "matrix has no real principal logarithm")
+            }
+        }
+    }
+}
+
+impl Error for MatrixFunctionError {}
+
+// Padé approximants of exp, from N. J. Higham,
+// "The Scaling and Squaring Method for the Matrix Exponential Revisited", 2005.
+// Each degree is used when the 1-norm of the matrix is lower than its theta.
+
+const PADE_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const PADE_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const PADE_7: [f64; 8] = [
+    17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.0,
+    8821612800.0,
+    2075673600.0,
+    302702400.0,
+    30270240.0,
+    2162160.0,
+    110880.0,
+    3960.0,
+    90.0,
+    1.0,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+const THETAS: [(f64, &[f64]); 4] = [
+    (1.495585217958292e-2, &PADE_3),
+    (2.53939833006323e-1, &PADE_5),
+    (9.504178996162932e-1, &PADE_7),
+    (2.097847961257068e0, &PADE_9),
+];
+const THETA_13: f64 = 5.371920351148152e0;
+
+// 8-point Gauss-Legendre quadrature on [0, 1], used for the Padé approximant of log(I + X)
+const GAUSS_NODES: [f64; 4] = [
+    0.1834346424956498,
+    0.525532409916329,
+    0.7966664774136267,
+    0.9602898564975363,
+];
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.362683783378362,
+    0.3137066458778873,
+    0.2223810344533745,
+    0.1012285362903763,
+];
+
+const MAX_ITERATIONS: usize = 100;
+
+impl<T: Real> Matrix<T> {
+    /// Compute the exponential of a square matrix,
+    /// using scaling and squaring with Padé approximants (Higham, 2005).
+    ///
+    /// # Accuracy
+    /// The degree of the approximant and the scaling are chosen so that the
+    /// result is backward stable for `f64`: it is the exact exponential of
+    /// `A + E`, with `‖E‖ ≤ ε‖A‖` up to rounding errors in the evaluation.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.  
+    /// Panics if its 1-norm is not finite, i.e. if it has infinite or NaN cells,
+    /// or cells so large that their sum overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// let zero: Matrix<f64> = Matrix::new(2, 2);
+    /// let exp = zero.expm();
+    ///
+    /// assert_eq!(exp, Matrix::from_iter(2, 2, vec![1.0, 0.0, 0.0, 1.0]));
+    /// ```
+    pub fn expm(&self) -> Matrix<T> {
+        assert!(self.rows == self.cols);
+
+        let norm = self.norm(Norm::One).to_f64();
+        assert!(norm.is_finite(), "expm requires a finite norm");
+        for &(theta, coefs) in THETAS.iter() {
+            if norm <= theta {
+                return pade_exp(self, coefs);
+            }
+        }
+
+        // Scale A so that its norm is below theta_13, then square the result back
+        let squarings = if norm > THETA_13 {
+            (norm / THETA_13).log2().ceil() as i32
+        } else {
+            0
+        };
+        let scale = T::from_f64(2f64.powi(-squarings));
+        let mut scaled = self.clone();
+        scaled.apply_mut(|v| *v *= scale);
+
+        let mut exp = pade_exp(&scaled, &PADE_13);
+        for _ in 0..squarings {
+            exp = matmul(&exp, &exp);
+        }
+        exp
+    }
+
+    /// Compute the principal square root of a square matrix,
+    /// i.e. the unique square root whose eigenvalues have positive real parts,
+    /// using the scaled Denman-Beavers iteration.
+    ///
+    /// # Accuracy
+    /// The iteration stops once it has converged to the working precision.
+    /// The relative residual `‖X² - A‖ / ‖A‖` is then typically of order `N·ε`,
+    /// but grows with the condition number of the square root.
+    ///
+    /// # Errors
+    /// Returns `NoRealSquareRoot` if the matrix has eigenvalues
+    /// on the closed negative real axis (including singular matrices).
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![4.0, 0.0, 0.0, 9.0]);
+    /// let sqrt = mat.sqrtm().unwrap();
+    ///
+    /// assert!(sqrt.approx_eq(&Matrix::from_iter(2, 2, vec![2.0, 0.0, 0.0, 3.0]), 1e-12, 0.0));
+    /// ```
+    pub fn sqrtm(&self) -> Result<Matrix<T>, MatrixFunctionError> {
+        assert!(self.rows == self.cols);
+
+        denman_beavers(self).ok_or(MatrixFunctionError::NoRealSquareRoot)
+    }
+
+    /// Compute the principal logarithm of a square matrix,
+    /// i.e. the unique logarithm whose eigenvalues have imaginary parts in `(-π, π)`,
+    /// using the inverse scaling and squaring method:
+    /// square roots are taken until the matrix is close to the identity,
+    /// then a Padé approximant of `log(I + X)` is evaluated.
+    ///
+    /// # Accuracy
+    /// The Padé approximant is accurate to the working precision,
+    /// but each square root amplifies the rounding errors:
+    /// the relative error is typically of order `1e-12` for `f64`.
+    ///
+    /// # Errors
+    /// Returns `NoRealLogarithm` if the matrix has eigenvalues
+    /// on the closed negative real axis (including singular matrices).
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, 1.0, 0.0, 1.0]);
+    /// let log = mat.logm().unwrap();
+    ///
+    /// assert!(log.expm().approx_eq(&mat, 1e-12, 0.0));
+    /// ```
+    pub fn logm(&self) -> Result<Matrix<T>, MatrixFunctionError> {
+        assert!(self.rows == self.cols);
+
+        let n = self.rows;
+        let threshold = T::from_f64(0.25);
+        let ident: Matrix<T> = identity(n);
+
+        let mut root = self.clone();
+        let mut doublings = 0;
+        let mut x = diff(&root, &ident);
+        while x.norm(Norm::One) > threshold {
+            if doublings == MAX_ITERATIONS {
+                return Err(MatrixFunctionError::NoRealLogarithm);
+            }
+            root = denman_beavers(&root).ok_or(MatrixFunctionError::NoRealLogarithm)?;
+            x = diff(&root, &ident);
+            doublings += 1;
+        }
+
+        // log(I + X) = ∫ X (I + tX)⁻¹ dt over [0, 1]
+        let mut log = Matrix::from_iter(n, n, (0..).map(|_| T::zero()));
+        for (&node, &weight) in GAUSS_NODES.iter().zip(GAUSS_WEIGHTS.iter()) {
+            for &t in [0.5 - node / 2.0, 0.5 + node / 2.0].iter() {
+                let shifted = lin_comb(&[(T::from_f64(t), &x), (T::one(), &ident)]);
+
+                let lu = Lu::new(&shifted).ok_or(MatrixFunctionError::NoRealLogarithm)?;
+                let term = lu.solve(&x);
+                let weight = T::from_f64(weight / 2.0);
+                log.data
+                    .iter_mut()
+                    .zip(term.data.iter())
+                    .for_each(|(l, &v)| *l += weight * v);
+            }
+        }
+
+        let scale = T::from_f64(2f64.powi(doublings as i32));
+        log.apply_mut(|v| *v *= scale);
+        Ok(log)
+    }
+}
+
+/// Evaluate the [m/m] Padé approximant of exp at a square matrix,
+/// given the coefficients of its numerator.
+fn pade_exp<T: Real>(a: &Matrix<T>, coefs: &[f64]) -> Matrix<T> {
+    let n = a.rows;
+    let c = |i: usize| T::from_f64(coefs[i]);
+    let ident = identity(n);
+    let a2 = matmul(a, a);
+
+    // exp(A) ≈ (V - U)⁻¹ (V + U),
+    // with U the odd terms and V the even terms of the numerator
+    let (u, v) = if coefs.len() == PADE_13.len() {
+        let a4 = matmul(&a2, &a2);
+        let a6 = matmul(&a4, &a2);
+
+        let u_high = lin_comb(&[(c(13), &a6), (c(11), &a4), (c(9), &a2)]);
+        let u_low = lin_comb(&[(c(7), &a6), (c(5), &a4), (c(3), &a2), (c(1), &ident)]);
+        let u = matmul(a, &add(&matmul(&a6, &u_high), &u_low));
+
+        let v_high = lin_comb(&[(c(12), &a6), (c(10), &a4), (c(8), &a2)]);
+        let v_low = lin_comb(&[(c(6), &a6), (c(4), &a4), (c(2), &a2), (c(0), &ident)]);
+        let v = add(&matmul(&a6, &v_high), &v_low);
+
+        (u, v)
+    } else {
+        // Even powers of A: I, A², A⁴, ...
+        let mut powers = vec![ident, a2];
+        while powers.len() < coefs.len() / 2 {
+            let next = matmul(&powers[powers.len() - 1], &powers[1]);
+            powers.push(next);
+        }
+
+        let odd: Vec<_> = powers
+            .iter()
+            .enumerate()
+            .map(|(k, p)| (c(2 * k + 1), p))
+            .collect();
+        let even: Vec<_> = powers
+            .iter()
+            .enumerate()
+            .map(|(k, p)| (c(2 * k), p))
+            .collect();
+
+        (matmul(a, &lin_comb(&odd)), lin_comb(&even))
+    };
+
+    let lu = Lu::new(&diff(&v, &u)).expect("Padé denominator is invertible for finite norms");
+    lu.solve(&add(&v, &u))
+}
+
+/// Scaled Denman-Beavers iteration:
+/// `Y ← (Y + Z⁻¹) / 2`, `Z ← (Z + Y⁻¹) / 2`, with `Y → √A` and `Z → √A⁻¹`.
+/// Returns `None` if an iterate is singular or if the iteration does not converge.
+fn denman_beavers<T: Real>(a: &Matrix<T>) -> Option<Matrix<T>> {
+    let n = a.rows;
+    let half = T::from_f64(0.5);
+    let tol = T::from_f64(n as f64) * T::epsilon();
+
+    let mut y = a.clone();
+    let mut z = identity(n);
+    let mut scaling = true;
+    let mut finishing = false;
+
+    for _ in 0..MAX_ITERATIONS {
+        let lu_y = Lu::new(&y)?;
+        let lu_z = Lu::new(&z)?;
+
+        // Determinant scaling speeds up the initial phase of the iteration
+        let mu = if scaling {
+            let det = (lu_y.determinant() * lu_z.determinant()).abs();
+            T::from_f64(det.to_f64().powf(-0.5 / n as f64))
+        } else {
+            T::one()
+        };
+        if !mu.is_finite() {
+            return None;
+        }
+
+        let y_inv = lu_y.inverse();
+        let z_inv = lu_z.inverse();
+        let next_y = lin_comb(&[(half * mu, &y), (half / mu, &z_inv)]);
+        let next_z = lin_comb(&[(half * mu, &z), (half / mu, &y_inv)]);
+
+        let change = diff(&next_y, &y).norm(Norm::Frobenius);
+        let size = next_y.norm(Norm::Frobenius);
+        if !size.is_finite() {
+            return None;
+        }
+
+        y = next_y;
+        z = next_z;
+
+        if finishing || change <= tol * size {
+            // Reject spurious convergence, e.g. from a chaotic iteration
+            let residual = diff(&matmul(&y, &y), a).norm(Norm::Frobenius);
+            return if residual <= tol.sqrt() * a.norm(Norm::Frobenius) {
+                Some(y)
+            } else {
+                None
+            };
+        }
+
+        // The convergence is quadratic: one more iteration reaches the working precision
+        if change <= tol.sqrt() * size {
+            scaling = false;
+            finishing = true;
+        }
+    }
+
+    None
+}
+
+/// Construct `Σ cᵢ Mᵢ`.
+fn lin_comb<T: Real>(terms: &[(T, &Matrix<T>)]) -> Matrix<T> {
+    let (c0, m0) = terms[0];
+    let mut acc = m0.clone();
+    acc.apply_mut(|v| *v *= c0);
+
+    for &(c, m) in &terms[1..] {
+        acc.data
+            .iter_mut()
+            .zip(m.data.iter())
+            .for_each(|(a, &v)| *a += c * v);
+    }
+    acc
+}
+
+fn add<T: Real>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    lin_comb(&[(T::one(), a), (T::one(), b)])
+}
+
+fn diff<T: Real>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    lin_comb(&[(T::one(), a), (-T::one(), b)])
+}
@@ -0,0 +1,537 @@
+/*!
+Iterative solvers for large linear systems `A * x = b`.
+
+The solvers only need to compute matrix-vector products,
+so they work with any operator implementing `LinearOperator`,
+including `Matrix<T>` itself.
+
+# Example
+```
+use simple_matrix::iterative::{cg, Jacobi, Settings};
+
+let a: Matrix<f64> = Matrix::from_iter(2, 2, vec![4.0, 1.0, 1.0, 3.0]);
+let jacobi = Jacobi::new(&a);
+
+let solution = cg(&a, &[1.0, 2.0], &Settings {
+    tol: 1e-12,
+    preconditioner: Some(&jacobi),
+    ..Settings::default()
+});
+
+assert!(solution.converged);
+```
+*/
+
+use super::{Matrix, Real};
+
+/// A linear operator `A`, only known through its products `A * x`.
+pub trait LinearOperator<T> {
+    /// Returns the number of rows of the operator.
+    fn rows(&self) -> usize;
+
+    /// Returns the number of columns of the operator.
+    fn cols(&self) -> usize;
+
+    /// Compute `y = A * x`.
+    /// `x` has `cols()` values, and `y` has `rows()` values.
+    fn mul_vec(&self, x: &[T], y: &mut [T]);
+}
+
+impl<T: Real> LinearOperator<T> for Matrix<T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn mul_vec(&self, x: &[T], y: &mut [T]) {
+        assert!(x.len() == self.cols);
+        assert!(y.len() == self.rows);
+
+        for (row, y) in self.data.chunks(self.cols).zip(y.iter_mut()) {
+            *y = dot(row, x);
+        }
+    }
+}
+
+/// A preconditioner `M`, approximating the operator `A`
+/// while being cheap to invert.
+pub trait Preconditioner<T> {
+    /// Solve `M * z = r` for `z`.
+    fn precondition(&self, r: &[T], z: &mut [T]);
+}
+
+/// Jacobi preconditioner: `M` is the diagonal of `A`.
+#[derive(Clone, Debug)]
+pub struct Jacobi<T> {
+    inv_diag: Vec<T>,
+}
+
+impl<T: Real> Jacobi<T> {
+    /// Construct the Jacobi preconditioner of a square matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square, or if a diagonal cell is zero.
+    pub fn new(a: &Matrix<T>) -> Jacobi<T> {
+        assert!(a.rows == a.cols);
+
+        Jacobi {
+            inv_diag: (0..a.rows)
+                .map(|i| {
+                    let d = a.data[i + i * a.cols];
+                    assert!(d != T::zero());
+                    T::one() / d
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T: Real> Preconditioner<T> for Jacobi<T> {
+    fn precondition(&self, r: &[T], z: &mut [T]) {
+        for ((z, &r), &d) in z.iter_mut().zip(r).zip(&self.inv_diag) {
+            *z = r * d;
+        }
+    }
+}
+
+/// Symmetric successive over-relaxation preconditioner:
+/// with `A = L + D + U`,
+/// `M = ω / (2 - ω) * (D / ω + L) * (D / ω)⁻¹ * (D / ω + U)`.
+#[derive(Clone, Debug)]
+pub struct Ssor<T> {
+    a: Matrix<T>,
+    omega: T,
+}
+
+impl<T: Real> Ssor<T> {
+    /// Construct the SSOR preconditioner of a square matrix,
+    /// with the relaxation factor `omega`.
+    /// `omega = 1` gives the symmetric Gauss-Seidel preconditioner.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square, if a diagonal cell is zero,
+    /// or if `omega` is not in the range `(0, 2)`.
+    pub fn new(a: &Matrix<T>, omega: T) -> Ssor<T> {
+        assert!(a.rows == a.cols);
+        assert!(omega > T::zero() && omega < T::from_f64(2.0));
+        assert!((0..a.rows).all(|i| a.data[i + i * a.cols] != T::zero()));
+
+        Ssor {
+            a: a.clone(),
+            omega,
+        }
+    }
+}
+
+impl<T: Real> Preconditioner<T> for Ssor<T> {
+    fn precondition(&self, r: &[T], z: &mut [T]) {
+        let n = self.a.rows;
+        let a = &self.a.data;
+        let omega = self.omega;
+
+        // Forward substitution: (D / ω + L) y = r
+        for i in 0..n {
+            let mut acc = r[i];
+            for j in 0..i {
+                acc -= a[j + i * n] * z[j];
+            }
+            z[i] = acc * omega / a[i + i * n];
+        }
+
+        // Scaling: y ← (D / ω) y
+        for i in 0..n {
+            z[i] *= a[i + i * n] / omega;
+        }
+
+        // Back substitution: (D / ω + U) z = y
+        for i in (0..n).rev() {
+            let mut acc = z[i];
+            for j in i + 1..n {
+                acc -= a[j + i * n] * z[j];
+            }
+            z[i] = acc * omega / a[i + i * n];
+        }
+
+        let scale = (T::from_f64(2.0) - omega) / omega;
+        z.iter_mut().for_each(|z| *z *= scale);
+    }
+}
+
+/// Settings shared by the iterative solvers.
+#[derive(Clone, Copy)]
+pub struct Settings<'a, T> {
+    /// The solvers stop once `‖b - A * x‖ <= tol * ‖b‖`.
+    pub tol: T,
+    /// Maximum number of iterations (matrix-vector products for GMRES).
+    pub max_iter: usize,
+    /// Number of iterations between two restarts of GMRES.
+    pub restart: usize,
+    /// Initial guess, defaults to zero.
+    pub x0: Option<&'a [T]>,
+    /// Optional preconditioner.
+    pub preconditioner: Option<&'a dyn Preconditioner<T>>,
+}
+
+impl<'a, T: Real> Default for Settings<'a, T> {
+    /// `tol` defaults to `1e-8`, `max_iter` to `1000` and `restart` to `30`.
+    fn default() -> Self {
+        Settings {
+            tol: T::from_f64(1e-8),
+            max_iter: 1000,
+            restart: 30,
+            x0: None,
+            preconditioner: None,
+        }
+    }
+}
+
+/// Result of an iterative solver.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solution<T> {
+    /// The last approximation of the solution.
+    pub x: Vec<T>,
+    /// Whether the requested tolerance has been reached.
+    pub converged: bool,
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Relative residual norm `‖b - A * x‖ / ‖b‖` before the first iteration,
+    /// then after each iteration.
+    pub history: Vec<T>,
+}
+
+/// Solve `A * x = b` with the (preconditioned) Conjugate Gradient method.
+/// `A`, and the preconditioner if any, must be symmetric positive definite.
+///
+/// # Panics
+/// Panics if `A` is not square, or if its size does not match `b`.
+pub fn cg<T, A>(a: &A, b: &[T], settings: &Settings<T>) -> Solution<T>
+where
+    T: Real,
+    A: LinearOperator<T> + ?Sized,
+{
+    let mut state = State::new(a, b, settings);
+    let n = b.len();
+
+    let mut r = state.residual(a, b);
+    let mut z = vec![T::zero(); n];
+    let mut ap = vec![T::zero(); n];
+
+    state.precondition(&r, &mut z);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+
+    while !state.done(norm(&r)) {
+        a.mul_vec(&p, &mut ap);
+        let alpha = rz / dot(&p, &ap);
+        axpy(alpha, &p, &mut state.x);
+        axpy(-alpha, &ap, &mut r);
+
+        state.precondition(&r, &mut z);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for (p, &z) in p.iter_mut().zip(&z) {
+            *p = z + beta * *p;
+        }
+    }
+
+    state.finish()
+}
+
+/// Solve `A * x = b` with the (right-preconditioned) BiCGSTAB method,
+/// suited to non-symmetric operators.
+///
+/// # Panics
+/// Panics if `A` is not square, or if its size does not match `b`.
+pub fn bicgstab<T, A>(a: &A, b: &[T], settings: &Settings<T>) -> Solution<T>
+where
+    T: Real,
+    A: LinearOperator<T> + ?Sized,
+{
+    let mut state = State::new(a, b, settings);
+    let n = b.len();
+
+    let mut r = state.residual(a, b);
+    let mut r_hat = r.clone();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut p = vec![T::zero(); n];
+    let mut v = vec![T::zero(); n];
+    let mut p_hat = vec![T::zero(); n];
+    let mut s_hat = vec![T::zero(); n];
+    let mut t = vec![T::zero(); n];
+
+    loop {
+        if norm(&r) <= state.tol * state.b_norm && !state.history.is_empty() {
+            // The recursively updated residual drifts away from the true one,
+            // so confirm convergence, and restart from the true residual if needed
+            r = state.residual(a, b);
+            if norm(&r) > state.tol * state.b_norm {
+                r_hat.copy_from_slice(&r);
+                rho = T::one();
+                alpha = T::one();
+                omega = T::one();
+                p.iter_mut()
+                    .chain(v.iter_mut())
+                    .for_each(|x| *x = T::zero());
+            }
+        }
+        if state.done(norm(&r)) {
+            break;
+        }
+
+        let rho_next = dot(&r_hat, &r);
+        if rho_next == T::zero() || omega == T::zero() {
+            // Breakdown: the method cannot progress any further
+            break;
+        }
+
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        for ((p, &r), &v) in p.iter_mut().zip(&r).zip(&v) {
+            *p = r + beta * (*p - omega * v);
+        }
+
+        state.precondition(&p, &mut p_hat);
+        a.mul_vec(&p_hat, &mut v);
+        alpha = rho / dot(&r_hat, &v);
+
+        // r now holds s = r - α v
+        axpy(-alpha, &v, &mut r);
+        axpy(alpha, &p_hat, &mut state.x);
+
+        state.precondition(&r, &mut s_hat);
+        a.mul_vec(&s_hat, &mut t);
+        let tt = dot(&t, &t);
+        omega = if tt == T::zero() {
+            T::zero()
+        } else {
+            dot(&t, &r) / tt
+        };
+
+        axpy(omega, &s_hat, &mut state.x);
+        axpy(-omega, &t, &mut r);
+    }
+
+    state.finish()
+}
+
+/// Solve `A * x = b` with the (right-preconditioned) restarted GMRES method,
+/// suited to non-symmetric operators.
+/// The Krylov basis is rebuilt every `settings.restart` iterations.
+/// The history holds the residual norms estimated by GMRES,
+/// except at the end of each cycle where the true residual norm is computed:
+/// convergence is only reported once the latter reaches the tolerance.
+///
+/// # Panics
+/// Panics if `A` is not square, if its size does not match `b`,
+/// or if `settings.restart` is zero.
+pub fn gmres<T, A>(a: &A, b: &[T], settings: &Settings<T>) -> Solution<T>
+where
+    T: Real,
+    A: LinearOperator<T> + ?Sized,
+{
+    assert!(settings.restart > 0);
+
+    let mut state = State::new(a, b, settings);
+    let n = b.len();
+    let m = settings.restart;
+
+    let mut w = vec![T::zero(); n];
+    let mut z = vec![T::zero(); n];
+
+    let mut r = state.residual(a, b);
+    let mut beta = norm(&r);
+    if state.done(beta) {
+        return state.finish();
+    }
+
+    loop {
+        // Arnoldi process, with the Hessenberg matrix kept triangular by Givens rotations
+        let mut basis: Vec<Vec<T>> = vec![r.iter().map(|&r| r / beta).collect()];
+        let mut h: Vec<Vec<T>> = Vec::with_capacity(m);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(m);
+        let mut g = vec![beta];
+
+        for j in 0..m {
+            state.precondition(&basis[j], &mut z);
+            a.mul_vec(&z, &mut w);
+
+            // Modified Gram-Schmidt
+            let mut col = Vec::with_capacity(j + 2);
+            for v in &basis {
+                let hij = dot(&w, v);
+                axpy(-hij, v, &mut w);
+                col.push(hij);
+            }
+            let h_next = norm(&w);
+            col.push(h_next);
+
+            for (i, &(cs, sn)) in rotations.iter().enumerate() {
+                let (a, b) = (col[i], col[i + 1]);
+                col[i] = cs * a + sn * b;
+                col[i + 1] = -sn * a + cs * b;
+            }
+            let rho = col[j].hypot(col[j + 1]);
+            let (cs, sn) = if rho == T::zero() {
+                (T::one(), T::zero())
+            } else {
+                (col[j] / rho, col[j + 1] / rho)
+            };
+            col[j] = rho;
+            col.pop();
+            rotations.push((cs, sn));
+            h.push(col);
+
+            g.push(-sn * g[j]);
+            g[j] = cs * g[j];
+            beta = g[j + 1].abs();
+
+            // A zero h_next means the exact solution lies in the current Krylov space;
+            // the residual is recorded first, so that the history ends with it
+            let done = state.done(beta);
+            let lucky = h_next == T::zero();
+            if done || lucky || j + 1 == m {
+                break;
+            }
+            basis.push(w.iter().map(|&w| w / h_next).collect());
+        }
+
+        // Solve the triangular system H y = g, then x += M⁻¹ V y
+        let k = h.len();
+        let mut y = vec![T::zero(); k];
+        for i in (0..k).rev() {
+            let mut acc = g[i];
+            for (l, &yl) in y.iter().enumerate().skip(i + 1) {
+                acc -= h[l][i] * yl;
+            }
+            y[i] = acc / h[i][i];
+        }
+        for v in w.iter_mut() {
+            *v = T::zero();
+        }
+        for (v, &y) in basis.iter().zip(&y) {
+            axpy(y, v, &mut w);
+        }
+        state.precondition(&w, &mut z);
+        axpy(T::one(), &z, &mut state.x);
+
+        // The rotations only estimate the residual, which drifts with rounding errors
+        // or an inexact preconditioner: the true one is recorded in its place,
+        // and decides whether to stop or to restart from it
+        r = state.residual(a, b);
+        beta = norm(&r);
+        let relative = beta / state.b_norm;
+        if let Some(last) = state.history.last_mut() {
+            *last = relative;
+        }
+        state.converged = relative <= state.tol;
+        if state.converged || state.iterations >= state.max_iter || !relative.is_finite() {
+            break;
+        }
+    }
+
+    state.finish()
+}
+
+/// Book-keeping shared by the solvers.
+struct State<'a, T> {
+    x: Vec<T>,
+    b_norm: T,
+    tol: T,
+    max_iter: usize,
+    preconditioner: Option<&'a dyn Preconditioner<T>>,
+    iterations: usize,
+    converged: bool,
+    history: Vec<T>,
+}
+
+impl<'a, T: Real> State<'a, T> {
+    fn new<A>(a: &A, b: &[T], settings: &Settings<'a, T>) -> State<'a, T>
+    where
+        A: LinearOperator<T> + ?Sized,
+    {
+        assert!(a.rows() == a.cols());
+        assert!(a.rows() == b.len());
+
+        let x = match settings.x0 {
+            Some(x0) => {
+                assert!(x0.len() == b.len());
+                x0.to_vec()
+            }
+            None => vec![T::zero(); b.len()],
+        };
+        let b_norm = norm(b);
+
+        State {
+            x,
+            // A zero right-hand side is measured with absolute residuals
+            b_norm: if b_norm == T::zero() {
+                T::one()
+            } else {
+                b_norm
+            },
+            tol: settings.tol,
+            max_iter: settings.max_iter,
+            preconditioner: settings.preconditioner,
+            iterations: 0,
+            converged: false,
+            history: Vec::new(),
+        }
+    }
+
+    /// Compute `b - A * x`.
+    fn residual<A>(&self, a: &A, b: &[T]) -> Vec<T>
+    where
+        A: LinearOperator<T> + ?Sized,
+    {
+        let mut r = vec![T::zero(); b.len()];
+        a.mul_vec(&self.x, &mut r);
+        r.iter_mut().zip(b).for_each(|(r, &b)| *r = b - *r);
+        r
+    }
+
+    fn precondition(&self, r: &[T], z: &mut [T]) {
+        match self.preconditioner {
+            Some(m) => m.precondition(r, z),
+            None => z.copy_from_slice(r),
+        }
+    }
+
+    /// Record the residual norm of the current iterate,
+    /// and return `true` if the solver should stop.
+    fn done(&mut self, r_norm: T) -> bool {
+        if !self.history.is_empty() {
+            self.iterations += 1;
+        }
+        let relative = r_norm / self.b_norm;
+        self.history.push(relative);
+        self.converged = relative <= self.tol;
+
+        self.converged || self.iterations >= self.max_iter || !relative.is_finite()
+    }
+
+    fn finish(self) -> Solution<T> {
+        Solution {
+            x: self.x,
+            converged: self.converged,
+            iterations: self.iterations,
+            history: self.history,
+        }
+    }
+}
+
+fn dot<T: Real>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+}
+
+fn norm<T: Real>(a: &[T]) -> T {
+    dot(a, a).sqrt()
+}
+
+/// Compute `y += alpha * x`.
+fn axpy<T: Real>(alpha: T, x: &[T], y: &mut [T]) {
+    y.iter_mut().zip(x).for_each(|(y, &x)| *y += alpha * x);
+}
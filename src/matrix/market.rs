@@ -0,0 +1,468 @@
+//! Matrix Market exchange format, as described on <https://math.nist.gov/MatrixMarket/formats.html>.
+//!
+//! Dense matrices use the `array` format, listing every value column by column,
+//! and sparse matrices use the `coordinate` format, listing `row col value` triplets.
+
+use super::{CooMatrix, CscMatrix, CsrMatrix, Matrix, Ring};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+/// Type of the values of a Matrix Market file.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MatrixMarketField {
+    /// Floating-point values.
+    Real,
+    /// Integer values.
+    Integer,
+    /// No values: every listed entry is `1`. Only valid for the `coordinate` format.
+    Pattern,
+}
+
+/// Symmetry of a Matrix Market file.
+/// Symmetric files only list the cells on and below the diagonal,
+/// and skew-symmetric files only the cells strictly below it.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum MatrixMarketSymmetry {
+    /// Every cell is listed.
+    General,
+    /// `A[i][j] == A[j][i]`.
+    Symmetric,
+    /// `A[i][j] == -A[j][i]`, so the diagonal is zero.
+    SkewSymmetric,
+}
+
+/// Error returned when reading a Matrix Market file.
+#[derive(Debug)]
+pub enum MatrixMarketError {
+    /// The input could not be read, or is not valid UTF-8.
+    Io(io::Error),
+    /// A line of the input is malformed.
+    Syntax {
+        /// Line of the error, counted from `1`.
+        line: usize,
+        /// Description of the error.
+        message: String,
+    },
+    /// The file uses a valid qualifier which is not supported (e.g. `complex`).
+    Unsupported {
+        /// The unsupported qualifier.
+        qualifier: String,
+    },
+}
+
+impl fmt::Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixMarketError::Io(err) => write!(f, "cannot read Matrix Market file: {}", err),
+            MatrixMarketError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            MatrixMarketError::Unsupported { qualifier } => {
+                write!(f, "unsupported Matrix Market qualifier {:?}", qualifier)
+            }
+        }
+    }
+}
+
+impl Error for MatrixMarketError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MatrixMarketError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MatrixMarketError {
+    fn from(err: io::Error) -> Self {
+        MatrixMarketError::Io(err)
+    }
+}
+
+impl<T: Ring + FromStr> Matrix<T>
+where
+    T::Err: Display,
+{
+    /// Read a matrix from a Matrix Market file,
+    /// in either the `array` or the `coordinate` format.
+    /// Symmetric and skew-symmetric files are expanded to the full matrix,
+    /// and duplicate coordinates are summed.
+    ///
+    /// # Errors
+    /// Returns a `MatrixMarketError` if the input is malformed,
+    /// or uses the unsupported `complex` or `hermitian` qualifiers.
+    ///
+    /// # Examples
+    /// ```
+    /// let data = "%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n";
+    /// let mat: Matrix<i32> = Matrix::read_matrix_market(data.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 2, 1..));
+    /// ```
+    pub fn read_matrix_market<R: Read>(reader: R) -> Result<Matrix<T>, MatrixMarketError> {
+        let content = read(reader)?;
+        let (rows, cols) = (content.rows, content.cols);
+
+        let mut data = vec![T::zero(); rows * cols];
+        for (row, col, v) in content.entries {
+            let cell = &mut data[col + row * cols];
+            *cell = cell.clone() + v;
+        }
+
+        Ok(Matrix { rows, cols, data })
+    }
+}
+
+impl<T: Ring + FromStr> CooMatrix<T>
+where
+    T::Err: Display,
+{
+    /// Read a sparse matrix from a Matrix Market file,
+    /// in either the `coordinate` or the `array` format.
+    /// Symmetric and skew-symmetric files are expanded to the full matrix,
+    /// and the zeros of `array` files are not stored.
+    ///
+    /// # Errors
+    /// Returns a `MatrixMarketError` if the input is malformed,
+    /// or uses the unsupported `complex` or `hermitian` qualifiers.
+    ///
+    /// # Examples
+    /// ```
+    /// let data = "%%MatrixMarket matrix coordinate pattern symmetric\n3 3 2\n2 1\n3 3\n";
+    /// let coo: CooMatrix<f64> = CooMatrix::read_matrix_market(data.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(coo.nnz(), 3);
+    /// ```
+    pub fn read_matrix_market<R: Read>(reader: R) -> Result<CooMatrix<T>, MatrixMarketError> {
+        let content = read(reader)?;
+
+        let mut coo = CooMatrix::new(content.rows, content.cols);
+        for (row, col, v) in content.entries {
+            if content.format == Format::Coordinate || v != T::zero() {
+                coo.push(row, col, v);
+            }
+        }
+
+        Ok(coo)
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    /// Write the matrix in the Matrix Market `array` format.
+    /// With a symmetric or skew-symmetric `symmetry`, only the cells
+    /// on and below (or strictly below) the diagonal are written,
+    /// the matrix being assumed to have this symmetry.
+    ///
+    /// # Panics
+    /// Panics if `field` is `Pattern`, which is not valid for the `array` format,
+    /// or if the matrix is not square with a symmetric or skew-symmetric `symmetry`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    /// let mut out = Vec::new();
+    /// mat.write_matrix_market(&mut out, MatrixMarketField::Integer, MatrixMarketSymmetry::General).unwrap();
+    ///
+    /// assert_eq!(out, b"%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n");
+    /// ```
+    pub fn write_matrix_market<W: Write>(
+        &self,
+        mut writer: W,
+        field: MatrixMarketField,
+        symmetry: MatrixMarketSymmetry,
+    ) -> io::Result<()> {
+        assert!(field != MatrixMarketField::Pattern);
+        assert!(symmetry == MatrixMarketSymmetry::General || self.rows == self.cols);
+
+        write_banner(&mut writer, Format::Array, field, symmetry)?;
+        writeln!(writer, "{} {}", self.rows, self.cols)?;
+        for col in 0..self.cols {
+            for row in first_row(symmetry, col)..self.rows {
+                writeln!(writer, "{}", self.data[col + row * self.cols])?;
+            }
+        }
+
+        writer.flush()
+    }
+}
+
+macro_rules! impl_write_coordinate {
+    ($($ty:ident),*) => {
+        $(
+            impl<T: Display> $ty<T> {
+                /// Write the matrix in the Matrix Market `coordinate` format.
+                /// With a symmetric or skew-symmetric `symmetry`, only the entries
+                /// on and below (or strictly below) the diagonal are written,
+                /// the matrix being assumed to have this symmetry.
+                /// With the `Pattern` field, only the coordinates are written.
+                ///
+                /// # Panics
+                /// Panics if the matrix is not square with a symmetric or skew-symmetric `symmetry`.
+                pub fn write_matrix_market<W: Write>(
+                    &self,
+                    writer: W,
+                    field: MatrixMarketField,
+                    symmetry: MatrixMarketSymmetry,
+                ) -> io::Result<()> {
+                    write_coordinate(writer, self.rows(), self.cols(), self.iter(), field, symmetry)
+                }
+            }
+        )*
+    };
+}
+
+impl_write_coordinate!(CooMatrix, CsrMatrix, CscMatrix);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Array,
+    Coordinate,
+}
+
+/// First row stored in a column of a file with the given symmetry.
+fn first_row(symmetry: MatrixMarketSymmetry, col: usize) -> usize {
+    match symmetry {
+        MatrixMarketSymmetry::General => 0,
+        MatrixMarketSymmetry::Symmetric => col,
+        MatrixMarketSymmetry::SkewSymmetric => col + 1,
+    }
+}
+
+fn write_banner<W: Write>(
+    writer: &mut W,
+    format: Format,
+    field: MatrixMarketField,
+    symmetry: MatrixMarketSymmetry,
+) -> io::Result<()> {
+    let format = match format {
+        Format::Array => "array",
+        Format::Coordinate => "coordinate",
+    };
+    let field = match field {
+        MatrixMarketField::Real => "real",
+        MatrixMarketField::Integer => "integer",
+        MatrixMarketField::Pattern => "pattern",
+    };
+    let symmetry = match symmetry {
+        MatrixMarketSymmetry::General => "general",
+        MatrixMarketSymmetry::Symmetric => "symmetric",
+        MatrixMarketSymmetry::SkewSymmetric => "skew-symmetric",
+    };
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix {} {} {}",
+        format, field, symmetry
+    )
+}
+
+fn write_coordinate<'a, W, T, I>(
+    mut writer: W,
+    rows: usize,
+    cols: usize,
+    entries: I,
+    field: MatrixMarketField,
+    symmetry: MatrixMarketSymmetry,
+) -> io::Result<()>
+where
+    W: Write,
+    T: Display + 'a,
+    I: Iterator<Item = (usize, usize, &'a T)>,
+{
+    assert!(symmetry == MatrixMarketSymmetry::General || rows == cols);
+
+    let entries: Vec<_> = entries
+        .filter(|&(row, col, _)| row >= first_row(symmetry, col))
+        .collect();
+
+    write_banner(&mut writer, Format::Coordinate, field, symmetry)?;
+    writeln!(writer, "{} {} {}", rows, cols, entries.len())?;
+    for (row, col, v) in entries {
+        if field == MatrixMarketField::Pattern {
+            writeln!(writer, "{} {}", row + 1, col + 1)?;
+        } else {
+            writeln!(writer, "{} {} {}", row + 1, col + 1, v)?;
+        }
+    }
+
+    writer.flush()
+}
+
+/// Content of a Matrix Market file.
+struct Content<T> {
+    rows: usize,
+    cols: usize,
+    format: Format,
+    /// `(row, col, value)` triplets, with the symmetric entries expanded.
+    entries: Vec<(usize, usize, T)>,
+}
+
+/// Read a Matrix Market file.
+fn read<T, R>(mut reader: R) -> Result<Content<T>, MatrixMarketError>
+where
+    T: Ring + FromStr,
+    T::Err: Display,
+    R: Read,
+{
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let syntax = |line: usize, message: String| MatrixMarketError::Syntax { line, message };
+
+    // Banner
+    let mut lines = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+    let banner = lines.next().map_or("", |(_, l)| l).to_lowercase();
+    let words: Vec<&str> = banner.split_whitespace().collect();
+    if words.len() != 5 || words[0] != "%%matrixmarket" {
+        return Err(syntax(1, "expected a %%MatrixMarket banner".to_string()));
+    }
+    if words[1] != "matrix" {
+        return Err(MatrixMarketError::Unsupported {
+            qualifier: words[1].to_string(),
+        });
+    }
+    let format = match words[2] {
+        "array" => Format::Array,
+        "coordinate" => Format::Coordinate,
+        other => return Err(syntax(1, format!("unknown format {:?}", other))),
+    };
+    let field = match words[3] {
+        "real" | "double" => MatrixMarketField::Real,
+        "integer" => MatrixMarketField::Integer,
+        "pattern" if format == Format::Coordinate => MatrixMarketField::Pattern,
+        "complex" | "pattern" => {
+            return Err(MatrixMarketError::Unsupported {
+                qualifier: words[3].to_string(),
+            })
+        }
+        other => return Err(syntax(1, format!("unknown field {:?}", other))),
+    };
+    let symmetry = match words[4] {
+        "general" => MatrixMarketSymmetry::General,
+        "symmetric" => MatrixMarketSymmetry::Symmetric,
+        "skew-symmetric" => MatrixMarketSymmetry::SkewSymmetric,
+        "hermitian" => {
+            return Err(MatrixMarketError::Unsupported {
+                qualifier: words[4].to_string(),
+            })
+        }
+        other => return Err(syntax(1, format!("unknown symmetry {:?}", other))),
+    };
+
+    // Skip comments and blank lines
+    let mut lines = lines.filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('%'));
+    let last_line = input.lines().count();
+
+    let parse_index = |line: usize, token: &str, len: usize| -> Result<usize, MatrixMarketError> {
+        match token.parse::<usize>() {
+            Ok(i) if i >= 1 && i <= len => Ok(i - 1),
+            _ => Err(syntax(
+                line,
+                format!("invalid index {:?}, expected 1 to {}", token, len),
+            )),
+        }
+    };
+    let parse_value = |line: usize, token: &str| -> Result<T, MatrixMarketError> {
+        token
+            .parse()
+            .map_err(|err: T::Err| syntax(line, format!("cannot parse {:?}: {}", token, err)))
+    };
+
+    // Size line
+    let (line, size) = lines
+        .next()
+        .ok_or_else(|| syntax(last_line, "missing size line".to_string()))?;
+    let size: Vec<&str> = size.split_whitespace().collect();
+    let expected = if format == Format::Array { 2 } else { 3 };
+    if size.len() != expected {
+        return Err(syntax(line, format!("expected {} sizes", expected)));
+    }
+    let sizes = size
+        .iter()
+        .map(|s| s.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| syntax(line, format!("invalid size: {}", err)))?;
+    let (rows, cols) = (sizes[0], sizes[1]);
+    if rows == 0 || cols == 0 {
+        return Err(syntax(line, "matrix has no cell".to_string()));
+    }
+    if symmetry != MatrixMarketSymmetry::General && rows != cols {
+        return Err(syntax(line, "symmetric matrix is not square".to_string()));
+    }
+
+    // Entries, as `(row, col, value)` triplets
+    let mut entries = Vec::new();
+    match format {
+        Format::Array => {
+            for col in 0..cols {
+                for row in first_row(symmetry, col)..rows {
+                    let (line, value) = lines.next().ok_or_else(|| {
+                        syntax(
+                            last_line,
+                            format!("missing value ({}, {})", row + 1, col + 1),
+                        )
+                    })?;
+                    entries.push((row, col, parse_value(line, value.trim())?));
+                }
+            }
+        }
+        Format::Coordinate => {
+            let values = if field == MatrixMarketField::Pattern {
+                2
+            } else {
+                3
+            };
+            for i in 0..sizes[2] {
+                let (line, entry) = lines.next().ok_or_else(|| {
+                    syntax(
+                        last_line,
+                        format!("expected {} entries, found {}", sizes[2], i),
+                    )
+                })?;
+                let tokens: Vec<&str> = entry.split_whitespace().collect();
+                if tokens.len() != values {
+                    return Err(syntax(line, format!("expected {} values", values)));
+                }
+                let row = parse_index(line, tokens[0], rows)?;
+                let col = parse_index(line, tokens[1], cols)?;
+                if row < first_row(symmetry, col) {
+                    return Err(syntax(
+                        line,
+                        "entry outside of the lower triangle".to_string(),
+                    ));
+                }
+                let value = if field == MatrixMarketField::Pattern {
+                    T::one()
+                } else {
+                    parse_value(line, tokens[2])?
+                };
+                entries.push((row, col, value));
+            }
+        }
+    }
+    if let Some((line, _)) = lines.next() {
+        return Err(syntax(
+            line,
+            "unexpected data after the last entry".to_string(),
+        ));
+    }
+
+    // Expand the symmetric entries
+    if symmetry != MatrixMarketSymmetry::General {
+        for i in 0..entries.len() {
+            let (row, col, ref v) = entries[i];
+            if row != col {
+                let v = match symmetry {
+                    MatrixMarketSymmetry::SkewSymmetric => T::zero() - v.clone(),
+                    _ => v.clone(),
+                };
+                entries.push((col, row, v));
+            }
+        }
+    }
+
+    Ok(Content {
+        rows,
+        cols,
+        format,
+        entries,
+    })
+}
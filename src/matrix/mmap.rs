@@ -0,0 +1,465 @@
+use super::binary::{Crc32, Header, HEADER_LEN};
+use super::{BinaryElement, BinaryError, Matrix};
+use memmap2::{Mmap, MmapMut, MmapOptions};
+use std::fs::{File, OpenOptions};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::{io, mem, slice};
+
+/// A read-only matrix backed by a memory-mapped file in the format of `Matrix::save`,
+/// so that matrices larger than memory can be processed.
+/// Cells are only read from the disk when accessed,
+/// except for `bool` matrices whose cells are all checked on opening.
+///
+/// The file must have been written on a machine with the same endianness,
+/// and must not be modified by other processes while mapped.
+pub struct MmapMatrix<T> {
+    rows: usize,
+    cols: usize,
+    map: Mmap,
+    _cells: PhantomData<T>,
+}
+
+/// A read-write matrix backed by a memory-mapped file in the format of `Matrix::save`.
+/// Modifications are written back to the file by the operating system;
+/// the checksum of the file is updated by `flush`, or when the matrix is dropped.
+///
+/// The file must have been written on a machine with the same endianness,
+/// and must not be accessed by other processes while mapped.
+pub struct MmapMatrixMut<T> {
+    rows: usize,
+    cols: usize,
+    map: MmapMut,
+    dirty: bool,
+    _cells: PhantomData<T>,
+}
+
+/// A block of a memory-mapped matrix, returned by `MmapMatrix::tile` and `MmapMatrixMut::tile`.
+/// Its cells are borrowed from the mapped file, and only read from the disk when accessed.
+#[derive(Clone, Copy)]
+pub struct MmapTile<'a, T> {
+    rows: usize,
+    cols: usize,
+    /// Cells from the top-left to the bottom-right cell of the tile, row by row.
+    data: &'a [T],
+    /// Number of columns of the whole matrix.
+    stride: usize,
+}
+
+/// Validate the header and length of a mapped file, returning the size of the matrix.
+fn check<T: BinaryElement>(bytes: &[u8]) -> Result<(usize, usize), BinaryError> {
+    assert!(T::SIZE == mem::size_of::<T>() && T::SIZE <= 8);
+
+    if bytes.len() < HEADER_LEN {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated header").into());
+    }
+    let mut header = [0; HEADER_LEN];
+    header.copy_from_slice(&bytes[..HEADER_LEN]);
+    let decoded = Header::decode::<T>(&header)?;
+
+    if decoded.little_endian != cfg!(target_endian = "little") {
+        return Err(BinaryError::InvalidHeader(
+            "cells are not stored in the native endianness".to_string(),
+        ));
+    }
+    if bytes.len() != HEADER_LEN + decoded.data_len::<T>() + 4 {
+        return Err(BinaryError::InvalidHeader(format!(
+            "file length does not match the matrix size {}x{}",
+            decoded.rows, decoded.cols
+        )));
+    }
+    // Any other byte would not be a valid `bool`
+    if T::KIND == 'b' && bytes[HEADER_LEN..bytes.len() - 4].iter().any(|&b| b > 1) {
+        return Err(BinaryError::InvalidHeader(
+            "invalid boolean cell".to_string(),
+        ));
+    }
+
+    Ok((decoded.rows, decoded.cols))
+}
+
+/// Compute the checksum of a mapped file, stored in its last 4 bytes.
+fn checksum(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(&bytes[..bytes.len() - 4]);
+    crc.finish()
+}
+
+/// Checksum stored in the last 4 bytes of a mapped file.
+fn stored_checksum(bytes: &[u8]) -> u32 {
+    let mut stored = [0; 4];
+    stored.copy_from_slice(&bytes[bytes.len() - 4..]);
+    u32::from_le_bytes(stored)
+}
+
+impl<T: BinaryElement> MmapMatrix<T> {
+    /// Map a file written by `Matrix::save` or `MmapMatrixMut::create`.
+    /// The checksum is not verified, as it requires reading the whole file: see `verify`.
+    /// The whole file is still read for `bool` matrices, to check that every cell is `0` or `1`.
+    ///
+    /// # Errors
+    /// Returns a `BinaryError` if the file cannot be mapped, is not in the crate's binary format,
+    /// does not hold cells of type `T`, or is not in the native endianness.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: MmapMatrix<f64> = MmapMatrix::open("huge.smx")?;
+    /// let trace: f64 = (0..mat.rows()).map(|i| mat[(i, i)]).sum();
+    /// ```
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<MmapMatrix<T>, BinaryError> {
+        let file = File::open(path)?;
+        // The file must not be modified while mapped, as documented on the type
+        let map = unsafe { MmapOptions::new().map(&file)? };
+        let (rows, cols) = check::<T>(&map)?;
+
+        Ok(MmapMatrix {
+            rows,
+            cols,
+            map,
+            _cells: PhantomData,
+        })
+    }
+}
+
+impl<T: BinaryElement> MmapMatrixMut<T> {
+    /// Create a file holding a *M*x*N* matrix of zeros, and map it.
+    /// An existing file is overwritten.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are equal to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: MmapMatrixMut<f64> = MmapMatrixMut::create("huge.smx", 100_000, 100_000)?;
+    /// mat.set(0, 0, 1.0);
+    /// mat.flush()?;
+    /// ```
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        rows: usize,
+        cols: usize,
+    ) -> Result<MmapMatrixMut<T>, BinaryError> {
+        assert!(rows > 0 && cols > 0);
+
+        let mut header = Header::encode::<T>(rows, cols);
+        header[12] = if cfg!(target_endian = "little") { 0 } else { 1 };
+        let len = rows
+            .checked_mul(cols)
+            .and_then(|n| n.checked_mul(T::SIZE))
+            .and_then(|n| n.checked_add(HEADER_LEN + 4))
+            .ok_or_else(|| {
+                BinaryError::InvalidHeader(format!("invalid matrix size {}x{}", rows, cols))
+            })?;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len(len as u64)?;
+        let mut map = unsafe { MmapOptions::new().map_mut(&file)? };
+        map[..HEADER_LEN].copy_from_slice(&header);
+        check::<T>(&map)?;
+
+        let mut mat = MmapMatrixMut {
+            rows,
+            cols,
+            map,
+            dirty: true,
+            _cells: PhantomData,
+        };
+        mat.flush()?;
+        Ok(mat)
+    }
+
+    /// Map a file written by `Matrix::save` or `MmapMatrixMut::create` for reading and writing.
+    /// As with `MmapMatrix::open`, the whole file is read for `bool` matrices to check their cells.
+    ///
+    /// # Errors
+    /// Returns a `BinaryError` if the file cannot be mapped, is not in the crate's binary format,
+    /// does not hold cells of type `T`, or is not in the native endianness.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<MmapMatrixMut<T>, BinaryError> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        // The file must not be accessed while mapped, as documented on the type
+        let map = unsafe { MmapOptions::new().map_mut(&file)? };
+        let (rows, cols) = check::<T>(&map)?;
+
+        Ok(MmapMatrixMut {
+            rows,
+            cols,
+            map,
+            dirty: false,
+            _cells: PhantomData,
+        })
+    }
+
+    /// Try to get a mutable reference to the cell at given row & column.
+    /// Returns `None` if `row` or `col` is outside of the matrix.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            let cols = self.cols;
+            Some(&mut (**self)[col + row * cols])
+        } else {
+            None
+        }
+    }
+
+    /// Try to set the cell at given row & column to the given value.
+    /// Returns `false` if `row` or `col` is outside of the matrix.
+    /// Returns `true` if the cell has been modified.
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> bool {
+        if let Some(cell) = self.get_mut(row, col) {
+            *cell = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Copy `tile` into the matrix, with its top-left cell at the given row & column.
+    ///
+    /// # Panics
+    /// Panics if the tile does not fit in the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: MmapMatrixMut<f32> = MmapMatrixMut::create("huge.smx", 4096, 4096)?;
+    /// mat.set_tile(1024, 2048, &Matrix::from_iter(512, 512, (0..).map(|_| 1.0)));
+    /// ```
+    pub fn set_tile(&mut self, row: usize, col: usize, tile: &Matrix<T>) {
+        assert!(row + tile.rows <= self.rows && col + tile.cols <= self.cols);
+
+        let cols = self.cols;
+        for (r, values) in tile.data.chunks(tile.cols).enumerate() {
+            let start = col + (row + r) * cols;
+            (**self)[start..start + tile.cols].copy_from_slice(values);
+        }
+    }
+
+    /// Update the checksum of the file, and write the modified cells to the disk.
+    pub fn flush(&mut self) -> io::Result<()> {
+        if self.dirty {
+            let crc = checksum(&self.map);
+            let len = self.map.len();
+            self.map[len - 4..].copy_from_slice(&crc.to_le_bytes());
+            self.dirty = false;
+        }
+        self.map.flush()
+    }
+}
+
+impl<T> Drop for MmapMatrixMut<T> {
+    fn drop(&mut self) {
+        if self.dirty {
+            let crc = checksum(&self.map);
+            let len = self.map.len();
+            self.map[len - 4..].copy_from_slice(&crc.to_le_bytes());
+        }
+    }
+}
+
+macro_rules! impl_mmap_matrix {
+    ($($t:ident),*) => {
+        $(
+            impl<T: BinaryElement> $t<T> {
+                /// Returns the number of rows in the matrix.
+                pub fn rows(&self) -> usize {
+                    self.rows
+                }
+
+                /// Returns the number of columns in the matrix.
+                pub fn cols(&self) -> usize {
+                    self.cols
+                }
+
+                /// Try to get a reference to the value at given row & column.
+                /// Returns `None` if `row` or `col` is outside of the matrix.
+                pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+                    if row < self.rows && col < self.cols {
+                        Some(&(**self)[col + row * self.cols])
+                    } else {
+                        None
+                    }
+                }
+
+                /// Try to get an iterator of all cells of the requested row.
+                /// Returns `None` if given row is outside of the matrix.
+                pub fn get_row(&self, row: usize) -> Option<impl Iterator<Item = &T>> {
+                    if row < self.rows {
+                        Some((**self)[row * self.cols..(row + 1) * self.cols].iter())
+                    } else {
+                        None
+                    }
+                }
+
+                /// Try to get an iterator of all cells of the requested column.
+                /// Returns `None` if given column is outside of the matrix.
+                pub fn get_col(&self, col: usize) -> Option<impl Iterator<Item = &T>> {
+                    if col < self.cols {
+                        Some((**self)[col..].iter().step_by(self.cols))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Borrow the `rows`x`cols` block with its top-left cell at the given row & column,
+                /// e.g. to process the matrix tile by tile. No cell is copied.
+                ///
+                /// # Panics
+                /// Panics if either `rows` or `cols` are equal to `0`,
+                /// or if the block does not fit in the matrix.
+                pub fn tile(&self, row: usize, col: usize, rows: usize, cols: usize) -> MmapTile<'_, T> {
+                    assert!(rows > 0 && cols > 0);
+                    assert!(row + rows <= self.rows && col + cols <= self.cols);
+
+                    let start = col + row * self.cols;
+                    MmapTile {
+                        rows,
+                        cols,
+                        data: &(**self)[start..start + (rows - 1) * self.cols + cols],
+                        stride: self.cols,
+                    }
+                }
+
+                /// Copy the whole matrix into memory.
+                pub fn to_matrix(&self) -> Matrix<T> {
+                    Matrix {
+                        rows: self.rows,
+                        cols: self.cols,
+                        data: self.to_vec(),
+                    }
+                }
+
+                /// Verify the checksum of the file, reading it entirely.
+                ///
+                /// # Errors
+                /// Returns `BinaryError::ChecksumMismatch` if the file is corrupted.
+                pub fn verify(&self) -> Result<(), BinaryError> {
+                    let expected = stored_checksum(&self.map);
+                    let found = checksum(&self.map);
+                    if found == expected {
+                        Ok(())
+                    } else {
+                        Err(BinaryError::ChecksumMismatch { expected, found })
+                    }
+                }
+            }
+
+            impl<T: BinaryElement> Deref for $t<T> {
+                type Target = [T];
+
+                fn deref(&self) -> &[T] {
+                    let data = &self.map[HEADER_LEN..self.map.len() - 4];
+                    // The map is page aligned, and the header keeps the data 8-byte aligned
+                    assert!(data.as_ptr() as usize & (mem::align_of::<T>() - 1) == 0);
+                    // `BinaryElement` is sealed, and only implemented for primitive types
+                    // whose bit patterns are all valid, except `bool` whose cells are checked;
+                    // the size and endianness of the cells were checked on opening too
+                    unsafe { slice::from_raw_parts(data.as_ptr() as *const T, self.rows * self.cols) }
+                }
+            }
+
+            impl<T: BinaryElement> std::ops::Index<(usize, usize)> for $t<T> {
+                type Output = T;
+
+                fn index(&self, (row, col): (usize, usize)) -> &T {
+                    assert!(col < self.cols);
+                    &(**self)[col + row * self.cols]
+                }
+            }
+
+            impl<'a, T: BinaryElement> IntoIterator for &'a $t<T> {
+                type Item = &'a T;
+                type IntoIter = slice::Iter<'a, T>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.iter()
+                }
+            }
+        )*
+    };
+}
+
+impl_mmap_matrix!(MmapMatrix, MmapMatrixMut);
+
+impl<T: BinaryElement> DerefMut for MmapMatrixMut<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.dirty = true;
+        let len = self.map.len();
+        let data = &mut self.map[HEADER_LEN..len - 4];
+        assert!(data.as_ptr() as usize & (mem::align_of::<T>() - 1) == 0);
+        // See `Deref`
+        unsafe { slice::from_raw_parts_mut(data.as_mut_ptr() as *mut T, self.rows * self.cols) }
+    }
+}
+
+impl<T: BinaryElement> std::ops::IndexMut<(usize, usize)> for MmapMatrixMut<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.cols);
+        let cols = self.cols;
+        &mut (**self)[col + row * cols]
+    }
+}
+
+impl<'a, T: Copy> MmapTile<'a, T> {
+    /// Returns the number of rows in the tile.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the tile.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Try to get a reference to the value at given row & column of the tile.
+    /// Returns `None` if `row` or `col` is outside of the tile.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < self.rows && col < self.cols {
+            Some(&self.data[col + row * self.stride])
+        } else {
+            None
+        }
+    }
+
+    /// Try to get the cells of the requested row of the tile.
+    /// Returns `None` if given row is outside of the tile.
+    pub fn get_row(&self, row: usize) -> Option<&'a [T]> {
+        if row < self.rows {
+            let start = row * self.stride;
+            Some(&self.data[start..start + self.cols])
+        } else {
+            None
+        }
+    }
+
+    /// Try to get an iterator of all cells of the requested column of the tile.
+    /// Returns `None` if given column is outside of the tile.
+    pub fn get_col(&self, col: usize) -> Option<impl Iterator<Item = &'a T>> {
+        if col < self.cols {
+            Some(self.data[col..].iter().step_by(self.stride))
+        } else {
+            None
+        }
+    }
+
+    /// Copy the tile into memory.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_iter(
+            self.rows,
+            self.cols,
+            (0..self.rows).flat_map(|r| self.get_row(r).unwrap().iter().copied()),
+        )
+    }
+}
+
+impl<'a, T> std::ops::Index<(usize, usize)> for MmapTile<'a, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.rows && col < self.cols);
+        &self.data[col + row * self.stride]
+    }
+}
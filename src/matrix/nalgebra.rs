@@ -0,0 +1,60 @@
+use super::Matrix;
+use ::nalgebra::{DMatrix, DMatrixView, DMatrixViewMut, Dyn, RawStorage, Scalar, U1};
+
+// nalgebra stores matrices column by column: views are zero-copy thanks to strides,
+// but owned conversions have to reorder the cells
+
+impl<T: Scalar> Matrix<T> {
+    /// Borrow the matrix as a `nalgebra` view, without copying its cells.
+    /// The view steps over `cols` cells from one row to the next.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![2.0, 1.0, 1.0, 3.0]);
+    ///
+    /// assert_eq!(mat.as_nalgebra().determinant(), 5.0);
+    /// ```
+    pub fn as_nalgebra(&self) -> DMatrixView<'_, T, Dyn, U1> {
+        DMatrixView::from_slice_with_strides_generic(
+            &self.data,
+            Dyn(self.rows),
+            Dyn(self.cols),
+            Dyn(self.cols),
+            U1,
+        )
+    }
+
+    /// Borrow the matrix as a mutable `nalgebra` view, without copying its cells.
+    pub fn as_nalgebra_mut(&mut self) -> DMatrixViewMut<'_, T, Dyn, U1> {
+        DMatrixViewMut::from_slice_with_strides_generic(
+            &mut self.data,
+            Dyn(self.rows),
+            Dyn(self.cols),
+            Dyn(self.cols),
+            U1,
+        )
+    }
+}
+
+/// Moves the cells into column by column order.
+impl<T: Scalar> From<Matrix<T>> for DMatrix<T> {
+    fn from(mat: Matrix<T>) -> Self {
+        DMatrix::from_row_iterator(mat.rows, mat.cols, mat.data)
+    }
+}
+
+/// Clones the cells of any dynamically sized matrix or view.
+///
+/// # Panics
+/// Panics if the matrix is empty.
+impl<T: Scalar, S: RawStorage<T, Dyn, Dyn>> From<::nalgebra::Matrix<T, Dyn, Dyn, S>> for Matrix<T> {
+    fn from(mat: ::nalgebra::Matrix<T, Dyn, Dyn, S>) -> Self {
+        let (rows, cols) = mat.shape();
+        let mat = &mat;
+        Matrix::from_iter(
+            rows,
+            cols,
+            (0..rows).flat_map(|i| (0..cols).map(move |j| mat[(i, j)].clone())),
+        )
+    }
+}
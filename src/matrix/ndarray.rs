@@ -0,0 +1,66 @@
+use super::Matrix;
+use ::ndarray::{Array2, ArrayView2, ArrayViewMut2};
+
+// Both layouts are row by row, so views are zero-copy
+// and owned conversions reuse the allocation when possible
+
+impl<T> Matrix<T> {
+    /// Borrow the matrix as an `ndarray` view, without copying its cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 3, (1..).map(f64::from));
+    /// let view = mat.as_ndarray();
+    ///
+    /// assert_eq!(view.sum_axis(ndarray::Axis(0)), ndarray::arr1(&[5.0, 7.0, 9.0]));
+    /// ```
+    pub fn as_ndarray(&self) -> ArrayView2<'_, T> {
+        ArrayView2::from_shape((self.rows, self.cols), &self.data).unwrap()
+    }
+
+    /// Borrow the matrix as a mutable `ndarray` view, without copying its cells.
+    pub fn as_ndarray_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        ArrayViewMut2::from_shape((self.rows, self.cols), &mut self.data).unwrap()
+    }
+}
+
+/// Moves the cells without copying them.
+impl<T> From<Matrix<T>> for Array2<T> {
+    fn from(mat: Matrix<T>) -> Self {
+        Array2::from_shape_vec((mat.rows, mat.cols), mat.data).unwrap()
+    }
+}
+
+/// Moves the cells without copying them if the array is in standard (row by row) layout,
+/// clones them otherwise, e.g. for transposed arrays.
+///
+/// # Panics
+/// Panics if the array is empty.
+impl<T: Clone> From<Array2<T>> for Matrix<T> {
+    fn from(array: Array2<T>) -> Self {
+        let (rows, cols) = array.dim();
+        if !array.is_standard_layout() {
+            return Matrix::from_iter(rows, cols, array.iter().cloned());
+        }
+
+        // The cells are contiguous from the offset, which can be anywhere in the allocation
+        // if the array has been sliced in place
+        let (mut data, offset) = array.into_raw_vec_and_offset();
+        data.drain(..offset.unwrap_or(0));
+        data.truncate(rows * cols);
+
+        assert!(rows > 0 && cols > 0);
+        Matrix { rows, cols, data }
+    }
+}
+
+/// Clones the cells of the view.
+///
+/// # Panics
+/// Panics if the view is empty.
+impl<'a, T: Clone> From<ArrayView2<'a, T>> for Matrix<T> {
+    fn from(view: ArrayView2<'a, T>) -> Self {
+        let (rows, cols) = view.dim();
+        Matrix::from_iter(rows, cols, view.iter().cloned())
+    }
+}
@@ -0,0 +1,135 @@
+use super::{Matrix, Real};
+use std::ops::Add;
+
+/// Matrix norms, as computed by `Matrix::norm`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Norm {
+    /// Square root of the sum of the squared cells.
+    Frobenius,
+    /// Maximum absolute column sum.
+    One,
+    /// Maximum absolute row sum.
+    Inf,
+    /// Maximum absolute cell.
+    Max,
+    /// Largest singular value.
+    Spectral,
+}
+
+impl<T: Real> Matrix<T> {
+    /// Compute the requested norm of the matrix.
+    ///
+    /// # Panics
+    /// Panics if `Norm::Spectral` is requested
+    /// and the matrix contains infinite or NaN cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, -2.0, 3.0, 4.0]);
+    ///
+    /// assert_eq!(mat.norm(Norm::One), 6.0);
+    /// assert_eq!(mat.norm(Norm::Inf), 7.0);
+    /// assert_eq!(mat.norm(Norm::Max), 4.0);
+    /// ```
+    pub fn norm(&self, norm: Norm) -> T {
+        match norm {
+            Norm::Frobenius => self.data.iter().fold(T::zero(), |acc, &v| acc.hypot(v)),
+            Norm::One => (0..self.cols)
+                .map(|col| {
+                    self.get_col(col)
+                        .unwrap()
+                        .fold(T::zero(), |acc, &v| acc + v.abs())
+                })
+                .fold(T::zero(), T::max),
+            Norm::Inf => (0..self.rows)
+                .map(|row| {
+                    self.get_row(row)
+                        .unwrap()
+                        .fold(T::zero(), |acc, &v| acc + v.abs())
+                })
+                .fold(T::zero(), T::max),
+            Norm::Max => self.data.iter().fold(T::zero(), |acc, &v| acc.max(v.abs())),
+            Norm::Spectral => self.svd().sigma[0],
+        }
+    }
+
+    /// Compute the entry-wise *p*-norm of the matrix,
+    /// i.e. the *p*-norm of its cells seen as a single vector.  
+    /// `p = 2` is the Frobenius norm, and `p = ∞` is the max norm.
+    ///
+    /// # Panics
+    /// Panics if `p < 1`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, -2.0, 3.0, 4.0]);
+    ///
+    /// assert_eq!(mat.entrywise_norm(1.0), 10.0);
+    /// ```
+    pub fn entrywise_norm(&self, p: T) -> T {
+        assert!(p >= T::one());
+
+        if p == T::infinity() {
+            self.norm(Norm::Max)
+        } else {
+            self.data
+                .iter()
+                .fold(T::zero(), |acc, &v| acc + v.abs().powf(p))
+                .powf(T::one() / p)
+        }
+    }
+
+    /// Compute the distance between two matrices,
+    /// i.e. the requested norm of their difference.
+    ///
+    /// # Panics
+    /// Panics if the matrices do not have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// let a: Matrix<f64> = Matrix::from_iter(1, 2, vec![1.0, 2.0]);
+    /// let b: Matrix<f64> = Matrix::from_iter(1, 2, vec![1.0, 4.0]);
+    ///
+    /// assert_eq!(a.distance(&b, Norm::Max), 2.0);
+    /// ```
+    pub fn distance(&self, other: &Matrix<T>, norm: Norm) -> T {
+        assert!(self.rows == other.rows);
+        assert!(self.cols == other.cols);
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(&a, &b)| a - b)
+                .collect(),
+        }
+        .norm(norm)
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Returns the sum of the diagonal cells of a square matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 3, 0..);
+    ///
+    /// assert_eq!(mat.trace(), 12);
+    /// ```
+    pub fn trace(&self) -> T
+    where
+        T: Add<Output = T> + Clone,
+    {
+        assert!(self.rows == self.cols);
+
+        (1..self.rows).fold(self.data[0].clone(), |acc, i| {
+            acc + self.data[i + i * self.cols].clone()
+        })
+    }
+}
@@ -0,0 +1,289 @@
+//! NumPy `.npy` files, as described on <https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html>,
+//! and `.npz` archives of `.npy` files.
+
+use super::bytes::LeBytes;
+use super::Matrix;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Primitive cell types which can be stored in `.npy` files:
+/// `bool`, the integer types up to 64 bits, `f32` and `f64`.
+///
+/// This trait is sealed, and cannot be implemented outside of this crate.
+pub trait NpyElement: LeBytes {}
+
+impl<T: LeBytes> NpyElement for T {}
+
+/// Error returned when reading a `.npy` file or a `.npz` archive.
+#[derive(Debug)]
+pub enum NpyError {
+    /// The input could not be read.
+    Io(io::Error),
+    /// The input is not a valid `.npy` file.
+    InvalidHeader(String),
+    /// The dtype of the array does not match the cell type of the matrix.
+    DtypeMismatch {
+        /// Dtype matching the cell type, e.g. `<f4`.
+        expected: String,
+        /// Dtype of the array.
+        found: String,
+    },
+    /// The array is not 2-dimensional, or is empty.
+    InvalidShape(Vec<usize>),
+    /// The `.npz` archive is invalid.
+    InvalidArchive(String),
+    /// The `.npz` archive does not contain the requested array.
+    MissingArray(String),
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NpyError::Io(err) => write!(f, "cannot read .npy file: {}", err),
+            NpyError::InvalidHeader(message) => write!(f, "invalid .npy header: {}", message),
+            NpyError::DtypeMismatch { expected, found } => {
+                write!(f, "expected dtype {:?}, found {:?}", expected, found)
+            }
+            NpyError::InvalidShape(shape) => {
+                write!(f, "expected a non-empty 2-D array, found shape {:?}", shape)
+            }
+            NpyError::InvalidArchive(message) => write!(f, "invalid .npz archive: {}", message),
+            NpyError::MissingArray(name) => write!(f, "array {:?} not found in archive", name),
+        }
+    }
+}
+
+impl Error for NpyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NpyError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(err: io::Error) -> Self {
+        NpyError::Io(err)
+    }
+}
+
+impl<T: NpyElement> Matrix<T> {
+    /// Read a matrix from a `.npy` file holding a 2-D array,
+    /// stored in either C or Fortran order, and in either endianness.
+    ///
+    /// # Errors
+    /// Returns a `NpyError` if the input is not a valid `.npy` file,
+    /// if its dtype does not exactly match `T` (e.g. `float32` for `f32`),
+    /// or if the array is not 2-dimensional.
+    ///
+    /// # Examples
+    /// ```
+    /// let file = std::fs::File::open("weights.npy")?;
+    /// let weights: Matrix<f32> = Matrix::read_npy(std::io::BufReader::new(file))?;
+    /// ```
+    pub fn read_npy<R: Read>(mut reader: R) -> Result<Matrix<T>, NpyError> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic[..6] != MAGIC {
+            return Err(NpyError::InvalidHeader("missing magic string".to_string()));
+        }
+
+        let header_len = match magic[6] {
+            1 => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                usize::from(u16::from_le_bytes(len))
+            }
+            2 | 3 => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            version => {
+                return Err(NpyError::InvalidHeader(format!(
+                    "unsupported version {}",
+                    version
+                )))
+            }
+        };
+        let mut header = vec![0; header_len];
+        reader.read_exact(&mut header)?;
+        let header = String::from_utf8_lossy(&header);
+
+        // Header dictionary, e.g. `{'descr': '<f8', 'fortran_order': False, 'shape': (3, 4), }`
+        let descr = dict_value(&header, "descr")?.trim_matches(|c| c == '\'' || c == '"');
+        let fortran_order = match dict_value(&header, "fortran_order")? {
+            "True" => true,
+            "False" => false,
+            other => {
+                return Err(NpyError::InvalidHeader(format!(
+                    "invalid fortran_order {:?}",
+                    other
+                )))
+            }
+        };
+        let shape = dict_value(&header, "shape")?
+            .trim_matches(|c| c == '(' || c == ')')
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| s.trim_end_matches('L').parse())
+            .collect::<Result<Vec<usize>, _>>()
+            .map_err(|err| NpyError::InvalidHeader(format!("invalid shape: {}", err)))?;
+
+        let mut chars = descr.chars();
+        let order = chars.next();
+        let kind = chars.next();
+        let size = chars.as_str().parse::<usize>().ok();
+        let little_endian = match order {
+            Some('<') | Some('|') => true,
+            Some('>') => false,
+            Some('=') => cfg!(target_endian = "little"),
+            _ => {
+                return Err(NpyError::InvalidHeader(format!(
+                    "invalid descr {:?}",
+                    descr
+                )))
+            }
+        };
+        if kind != Some(T::KIND) || size != Some(T::SIZE) {
+            return Err(NpyError::DtypeMismatch {
+                expected: dtype::<T>(),
+                found: descr.to_string(),
+            });
+        }
+        if shape.len() != 2 || shape[0] == 0 || shape[1] == 0 {
+            return Err(NpyError::InvalidShape(shape));
+        }
+
+        let (rows, cols) = (shape[0], shape[1]);
+        let len = match rows.checked_mul(cols).and_then(|n| n.checked_mul(T::SIZE)) {
+            Some(len) => len,
+            None => {
+                return Err(NpyError::InvalidHeader(format!(
+                    "shape {:?} is too large",
+                    shape
+                )))
+            }
+        };
+
+        // Read through `take` so that a corrupted shape fails on EOF instead of allocating
+        let mut bytes = Vec::new();
+        reader.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(
+                io::Error::new(io::ErrorKind::UnexpectedEof, "truncated array data").into(),
+            );
+        }
+        let values = bytes
+            .chunks(T::SIZE)
+            .map(|b| T::from_bytes(b, little_endian));
+
+        Ok(if fortran_order {
+            Matrix::from_iter(cols, rows, values).transpose()
+        } else {
+            Matrix::from_iter(rows, cols, values)
+        })
+    }
+
+    /// Write the matrix as a `.npy` file, in C order and little endian.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 3, (0..).map(f64::from));
+    /// mat.write_npy(std::fs::File::create("mat.npy")?)?;
+    /// // In Python: numpy.load("mat.npy")
+    /// ```
+    pub fn write_npy<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            dtype::<T>(),
+            self.rows,
+            self.cols
+        );
+        // The data starts at a multiple of 64 bytes, after a newline-terminated header
+        let total = MAGIC.len() + 4 + header.len() + 1;
+        header.push_str(&" ".repeat((64 - total % 64) % 64));
+        header.push('\n');
+
+        let mut out =
+            Vec::with_capacity(MAGIC.len() + 4 + header.len() + self.data.len() * T::SIZE);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&[1, 0]);
+        out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        out.extend_from_slice(header.as_bytes());
+        for &v in &self.data {
+            v.write_le(&mut out);
+        }
+
+        writer.write_all(&out)?;
+        writer.flush()
+    }
+
+    /// Read a matrix from the array `name` of a `.npz` archive,
+    /// as written by `numpy.savez` or `numpy.savez_compressed`.
+    /// The `.npy` extension of `name` is optional.
+    ///
+    /// # Errors
+    /// Returns a `NpyError` if the archive is invalid, does not contain the array,
+    /// or if the array cannot be read by `read_npy`.
+    ///
+    /// # Examples
+    /// ```
+    /// // In Python: numpy.savez("model.npz", weights=w, bias=b)
+    /// let file = std::fs::File::open("model.npz")?;
+    /// let weights: Matrix<f32> = Matrix::read_npz(file, "weights")?;
+    /// ```
+    #[cfg(feature = "npz")]
+    pub fn read_npz<R: Read + io::Seek>(reader: R, name: &str) -> Result<Matrix<T>, NpyError> {
+        use zip::result::ZipError;
+
+        let archive_error = |err: ZipError| match err {
+            ZipError::Io(err) => NpyError::Io(err),
+            err => NpyError::InvalidArchive(err.to_string()),
+        };
+
+        let mut archive = zip::ZipArchive::new(reader).map_err(archive_error)?;
+        let file_name = if name.ends_with(".npy") {
+            name.to_string()
+        } else {
+            format!("{}.npy", name)
+        };
+        let file = match archive.by_name(&file_name) {
+            Err(ZipError::FileNotFound) => return Err(NpyError::MissingArray(name.to_string())),
+            file => file.map_err(archive_error)?,
+        };
+
+        Matrix::read_npy(io::BufReader::new(file))
+    }
+}
+
+/// Little endian NumPy dtype of `T`, e.g. `<f8` for `f64`.
+fn dtype<T: NpyElement>() -> String {
+    let order = if T::SIZE == 1 { '|' } else { '<' };
+    format!("{}{}{}", order, T::KIND, T::SIZE)
+}
+
+/// Find the raw value of `key` in the header dictionary.
+fn dict_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let missing = || NpyError::InvalidHeader(format!("missing key {:?}", key));
+
+    let start = header
+        .find(&format!("'{}'", key))
+        .or_else(|| header.find(&format!("\"{}\"", key)))
+        .ok_or_else(missing)?;
+    let rest = header[start + key.len() + 2..].trim_start();
+    let rest = rest.strip_prefix(':').ok_or_else(missing)?.trim_start();
+
+    // The value ends at the first comma, except for tuples
+    let end = if rest.starts_with('(') {
+        rest.find(')').map(|i| i + 1)
+    } else {
+        rest.find([',', '}'])
+    };
+    Ok(rest[..end.ok_or_else(missing)?].trim())
+}
@@ -0,0 +1,129 @@
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Cell types with an addition, a subtraction and a multiplication,
+/// as well as their respective identities.  
+/// Implemented for all primitive numeric types.
+pub trait Ring:
+    Clone + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// Returns the additive identity `0`.
+    fn zero() -> Self;
+    /// Returns the multiplicative identity `1`.
+    fn one() -> Self;
+}
+
+/// Ring cell types where every non-zero value can be divided by,
+/// with exact results (e.g. rational numbers or modular integers).  
+/// Not implemented for floating-point types, which only approximate a field.
+pub trait Field: Ring + Div<Output = Self> {}
+
+/// Floating-point cell types usable by the numerical algorithms of this crate.
+/// Implemented for `f32` and `f64`.
+pub trait Real:
+    Ring
+    + Copy
+    + Debug
+    + PartialOrd
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// Returns the positive infinity.
+    fn infinity() -> Self;
+    /// Returns the machine epsilon.
+    fn epsilon() -> Self;
+    /// Returns the smallest positive normal value.
+    fn min_positive() -> Self;
+
+    /// Converts a `f64` to `Self`, rounding if needed.
+    fn from_f64(v: f64) -> Self;
+    /// Converts `self` to a `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Returns the absolute value of `self`.
+    fn abs(self) -> Self;
+    /// Returns the square root of `self`.
+    fn sqrt(self) -> Self;
+    /// Raises `self` to a floating-point power.
+    fn powf(self, n: Self) -> Self;
+    /// Returns `sqrt(self² + other²)` without intermediate over/under-flow.
+    fn hypot(self, other: Self) -> Self;
+    /// Returns `true` if `self` is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+    /// Returns the number of representable values between `self` and `other`.  
+    /// Returns `u64::MAX` if either value is NaN.
+    fn ulps_distance(self, other: Self) -> u64;
+
+    /// Returns the maximum of `self` and `other`.
+    fn max(self, other: Self) -> Self {
+        if self < other {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the minimum of `self` and `other`.
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! impl_ring {
+    ($zero:expr, $one:expr, $($t:ident),*) => {
+        $(
+            impl Ring for $t {
+                fn zero() -> Self { $zero }
+                fn one() -> Self { $one }
+            }
+        )*
+    };
+}
+
+impl_ring!(0, 1, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_ring!(0.0, 1.0, f32, f64);
+
+macro_rules! impl_real {
+    ($($t:ident: $bits:ident),*) => {
+        $(
+            impl Real for $t {
+                fn infinity() -> Self { $t::INFINITY }
+                fn epsilon() -> Self { $t::EPSILON }
+                fn min_positive() -> Self { $t::MIN_POSITIVE }
+
+                fn from_f64(v: f64) -> Self { v as $t }
+                fn to_f64(self) -> f64 { f64::from(self) }
+
+                fn abs(self) -> Self { $t::abs(self) }
+                fn sqrt(self) -> Self { $t::sqrt(self) }
+                fn powf(self, n: Self) -> Self { $t::powf(self, n) }
+                fn hypot(self, other: Self) -> Self { $t::hypot(self, other) }
+                fn is_finite(self) -> bool { $t::is_finite(self) }
+
+                fn ulps_distance(self, other: Self) -> u64 {
+                    if self.is_nan() || other.is_nan() {
+                        return u64::MAX;
+                    }
+
+                    // Map the sign-magnitude bits to a monotonic integer scale
+                    let ordered = |v: $t| {
+                        let bits = v.to_bits() as $bits;
+                        if bits < 0 { $bits::MIN - bits } else { bits }
+                    };
+                    let distance = (i128::from(ordered(self)) - i128::from(ordered(other))).unsigned_abs();
+                    std::convert::TryFrom::try_from(distance).unwrap_or(u64::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_real!(f32: i32, f64: i64);
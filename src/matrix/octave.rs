@@ -0,0 +1,167 @@
+use super::Matrix;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Error returned when parsing a matrix from a string.
+///
+/// Rows and columns are counted from `1`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseMatrixError {
+    /// The string does not contain any element.
+    Empty,
+    /// The opening and closing brackets do not match.
+    UnbalancedBrackets,
+    /// A row does not have as many elements as the first one.
+    RaggedRow {
+        /// Position of the row.
+        row: usize,
+        /// Number of elements of the first row.
+        expected: usize,
+        /// Number of elements of this row.
+        found: usize,
+    },
+    /// An element could not be parsed.
+    InvalidElement {
+        /// Row of the element.
+        row: usize,
+        /// Column of the element.
+        col: usize,
+        /// Text of the element.
+        element: String,
+        /// Message of the parsing error.
+        message: String,
+    },
+}
+
+impl fmt::Display for ParseMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseMatrixError::Empty => write!(f, "matrix does not contain any element"),
+            ParseMatrixError::UnbalancedBrackets => write!(f, "unbalanced brackets"),
+            ParseMatrixError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {}: expected {} elements, found {}",
+                row, expected, found
+            ),
+            ParseMatrixError::InvalidElement {
+                row,
+                col,
+                element,
+                message,
+            } => write!(
+                f,
+                "row {}, column {}: cannot parse {:?}: {}",
+                row, col, element, message
+            ),
+        }
+    }
+}
+
+impl Error for ParseMatrixError {}
+
+/// Parses a matrix in MATLAB/Octave notation, e.g. `[1 2 3; 4 5 6]`.
+///
+/// Elements are separated by whitespace or commas,
+/// and rows by semicolons or line breaks, so that whitespace-separated blocks
+/// of text are also accepted. The surrounding brackets are optional.
+///
+/// # Examples
+/// ```
+/// let mat: Matrix<i32> = "[1 2 3; 4 5 6]".parse().unwrap();
+/// assert_eq!(mat, Matrix::from_iter(2, 3, 1..));
+///
+/// let mat: Matrix<f64> = "1.5, 2\n-3  4e2\n".parse().unwrap();
+/// assert_eq!(mat, Matrix::from_iter(2, 2, vec![1.5, 2.0, -3.0, 400.0]));
+/// ```
+impl<T: FromStr> FromStr for Matrix<T>
+where
+    T::Err: Display,
+{
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let inner = match (s.strip_prefix('['), s.strip_suffix(']')) {
+            (Some(_), Some(_)) if s.len() >= 2 => &s[1..s.len() - 1],
+            (None, None) => s,
+            _ => return Err(ParseMatrixError::UnbalancedBrackets),
+        };
+        if inner.contains(['[', ']']) {
+            return Err(ParseMatrixError::UnbalancedBrackets);
+        }
+
+        let mut rows = 0;
+        let mut cols = 0;
+        let mut data = Vec::new();
+        for line in inner.split([';', '\n']) {
+            let elements: Vec<&str> = line
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|e| !e.is_empty())
+                .collect();
+            if elements.is_empty() {
+                continue;
+            }
+
+            rows += 1;
+            if rows == 1 {
+                cols = elements.len();
+            } else if elements.len() != cols {
+                return Err(ParseMatrixError::RaggedRow {
+                    row: rows,
+                    expected: cols,
+                    found: elements.len(),
+                });
+            }
+
+            for (col, element) in (1..).zip(elements) {
+                let v =
+                    element
+                        .parse()
+                        .map_err(|err: T::Err| ParseMatrixError::InvalidElement {
+                            row: rows,
+                            col,
+                            element: element.to_string(),
+                            message: err.to_string(),
+                        })?;
+                data.push(v);
+            }
+        }
+
+        if rows == 0 {
+            return Err(ParseMatrixError::Empty);
+        }
+
+        Ok(Matrix { rows, cols, data })
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    /// Format the matrix in MATLAB/Octave notation, e.g. `[1 2 3; 4 5 6]`,
+    /// which can be parsed back with `str::parse`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, -0.5, 1e-10, 4.0]);
+    ///
+    /// assert_eq!(mat.to_octave_string(), "[1 -0.5; 0.0000000001 4]");
+    /// assert_eq!(mat.to_octave_string().parse::<Matrix<f64>>().unwrap(), mat);
+    /// ```
+    pub fn to_octave_string(&self) -> String {
+        let rows: Vec<String> = self
+            .data
+            .chunks(self.cols)
+            .map(|row| {
+                row.iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+        format!("[{}]", rows.join("; "))
+    }
+}
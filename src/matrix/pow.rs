@@ -0,0 +1,177 @@
+use super::dense::identity;
+use super::{Matrix, Ring};
+
+impl<T: Ring> Matrix<T> {
+    /// Raise a square matrix to the `n`-th power, using exponentiation by squaring.
+    /// Only `O(log n)` matrix products are computed,
+    /// and intermediate results reuse the same buffers.
+    /// `mat.pow(0)` is the identity matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// // Fibonacci numbers
+    /// let mat: Matrix<u64> = Matrix::from_iter(2, 2, vec![1, 1, 1, 0]);
+    ///
+    /// assert_eq!(mat.pow(10).get(0, 1).unwrap(), 55);
+    /// ```
+    pub fn pow(&self, n: u64) -> Matrix<T> {
+        assert!(self.rows == self.cols);
+
+        let len = self.rows;
+        let data = binary_pow(self, n, |a, b, out| {
+            out.clear();
+            for row in 0..len {
+                for col in 0..len {
+                    let mut acc = T::zero();
+                    for k in 0..len {
+                        acc = acc + a[k + row * len].clone() * b[col + k * len].clone();
+                    }
+                    out.push(acc);
+                }
+            }
+        });
+
+        match data {
+            Some(data) => Matrix {
+                rows: len,
+                cols: len,
+                data,
+            },
+            None => identity(len),
+        }
+    }
+}
+
+macro_rules! impl_pow_mod {
+    ($($t:ident),*) => {
+        $(
+            impl Matrix<$t> {
+                /// Raise a square integer matrix to the `n`-th power modulo `m`,
+                /// using exponentiation by squaring.
+                /// Products are reduced modulo `m` without ever overflowing,
+                /// including for 128-bit integers.
+                /// All cells of the result are in the range `[0, m)`.
+                ///
+                /// # Panics
+                /// Panics if the matrix is not square, or if `m <= 0`.
+                ///
+                /// # Examples
+                /// ```
+                /// // Fibonacci numbers
+                /// let mat: Matrix<u64> = Matrix::from_iter(2, 2, vec![1, 1, 1, 0]);
+                ///
+                /// assert_eq!(mat.pow_mod(1000, 1_000_000_007).get(0, 1).unwrap(), 517_691_607);
+                /// ```
+                pub fn pow_mod(&self, n: u64, m: $t) -> Matrix<$t> {
+                    assert!(self.rows == self.cols);
+                    assert!(m > 0);
+
+                    let len = self.rows;
+                    // Cells are reduced to [0, m) once, then handled as u128
+                    // so that signed and unsigned types share the arithmetic
+                    let wide_m = m as u128;
+                    let reduce = |v: $t| v.rem_euclid(m) as u128;
+
+                    let base = Matrix {
+                        rows: len,
+                        cols: len,
+                        data: self.data.iter().map(|&v| reduce(v)).collect(),
+                    };
+
+                    let data = binary_pow(&base, n, |a, b, out| {
+                        out.clear();
+                        for row in 0..len {
+                            for col in 0..len {
+                                let mut acc = 0;
+                                for k in 0..len {
+                                    let prod = mul_mod(a[k + row * len], b[col + k * len], wide_m);
+                                    acc = add_mod(acc, prod, wide_m);
+                                }
+                                out.push(acc);
+                            }
+                        }
+                    });
+
+                    match data {
+                        Some(data) => Matrix {
+                            rows: len,
+                            cols: len,
+                            // Values are always in [0, m), so they fit back in $t
+                            data: data.into_iter().map(|v| v as $t).collect(),
+                        },
+                        // Modulo 1, the identity is the zero matrix
+                        None => identity::<$t>(len).map(|v| v.rem_euclid(m)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_pow_mod!(u8, u16, u32, u64, u128);
+impl_pow_mod!(i8, i16, i32, i64, i128);
+
+/// Compute `(a + b) % m` for `a, b < m`, without overflowing.
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Compute `(a * b) % m` for `a, b < m`, without overflowing.
+/// Products which do not fit in a `u128` (only possible for 128-bit cells)
+/// are computed by doubling and adding.
+fn mul_mod(mut a: u128, mut b: u128, m: u128) -> u128 {
+    if let Some(prod) = a.checked_mul(b) {
+        return prod % m;
+    }
+
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// Compute `base^n` by squaring, where `mul_into(a, b, out)`
+/// stores the product of the square matrices `a` and `b` into `out`.
+/// Returns `None` if `n == 0`, the result being the identity matrix.
+fn binary_pow<T, F>(base: &Matrix<T>, mut n: u64, mul_into: F) -> Option<Vec<T>>
+where
+    T: Clone,
+    F: Fn(&[T], &[T], &mut Vec<T>),
+{
+    let len = base.rows;
+    let mut base = base.data.clone();
+    let mut scratch = Vec::with_capacity(len * len);
+    let mut result: Option<Vec<T>> = None;
+
+    while n > 0 {
+        if n & 1 == 1 {
+            result = Some(match result {
+                None => base.clone(),
+                Some(acc) => {
+                    mul_into(&acc, &base, &mut scratch);
+                    std::mem::replace(&mut scratch, acc)
+                }
+            });
+        }
+
+        n >>= 1;
+        if n > 0 {
+            mul_into(&base, &base, &mut scratch);
+            std::mem::swap(&mut base, &mut scratch);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,282 @@
+use super::{Matrix, Real, Ring};
+use std::io::{self, Write};
+
+/// Map from values normalized to `[0, 1]` to RGB colors.
+#[derive(Clone, Copy, Debug)]
+pub enum Colormap {
+    /// Perceptually uniform map from dark purple to yellow, as in matplotlib.
+    Viridis,
+    /// Map from black to white.
+    Grayscale,
+    /// Map from blue to red through white, for values around a center such as `0`.
+    Diverging,
+    /// Custom map.
+    Custom(fn(f64) -> [u8; 3]),
+}
+
+const VIRIDIS: [[u8; 3]; 9] = [
+    [68, 1, 84],
+    [72, 40, 120],
+    [62, 73, 137],
+    [49, 104, 142],
+    [38, 130, 142],
+    [31, 158, 137],
+    [53, 183, 121],
+    [110, 206, 88],
+    [253, 231, 37],
+];
+
+const DIVERGING: [[u8; 3]; 3] = [[59, 76, 192], [247, 247, 247], [180, 4, 38]];
+
+impl Colormap {
+    /// Color of `t`, clamped to `[0, 1]`.
+    pub fn color(&self, t: f64) -> [u8; 3] {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        match self {
+            Colormap::Viridis => interpolate(&VIRIDIS, t),
+            Colormap::Grayscale => {
+                let v = gray(t);
+                [v, v, v]
+            }
+            Colormap::Diverging => interpolate(&DIVERGING, t),
+            Colormap::Custom(map) => map(t),
+        }
+    }
+}
+
+/// Linear interpolation between evenly spaced colors.
+fn interpolate(colors: &[[u8; 3]], t: f64) -> [u8; 3] {
+    let x = t * (colors.len() - 1) as f64;
+    let i = (x.floor() as usize).min(colors.len() - 2);
+    let frac = x - i as f64;
+
+    let mut out = [0; 3];
+    for (c, out) in out.iter_mut().enumerate() {
+        let (a, b) = (f64::from(colors[i][c]), f64::from(colors[i + 1][c]));
+        *out = (a + (b - a) * frac).round() as u8;
+    }
+    out
+}
+
+fn gray(t: f64) -> u8 {
+    (t * 255.0).round() as u8
+}
+
+/// Range of values mapped to the colormap; values outside of it are clamped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValueRange {
+    /// From the smallest to the largest finite value of the matrix.
+    Auto,
+    /// From `-m` to `m`, `m` being the largest finite absolute value of the matrix,
+    /// so that `0` is at the center of diverging colormaps.
+    Symmetric,
+    /// From the first value to the second one.
+    Fixed(f64, f64),
+}
+
+/// Options of the rendering methods of `Matrix`.
+#[derive(Clone, Copy, Debug)]
+pub struct RenderOptions {
+    /// Colors of the cells. `Matrix::write_pgm` is always in grayscale.
+    pub colormap: Colormap,
+    /// Range of values mapped to the colormap.
+    pub range: ValueRange,
+    /// Width and height of a cell, in pixels, for images.
+    pub cell_size: usize,
+}
+
+impl Default for RenderOptions {
+    /// Viridis colormap over the range of the matrix, with one pixel per cell.
+    fn default() -> Self {
+        RenderOptions {
+            colormap: Colormap::Viridis,
+            range: ValueRange::Auto,
+            cell_size: 1,
+        }
+    }
+}
+
+/// Color of the cells which are not a number.
+const NAN_COLOR: [u8; 3] = [0, 0, 0];
+
+impl<T: Real> Matrix<T> {
+    /// Normalize the cells to `[0, 1]` row by row, `NaN` cells staying `NaN`.
+    fn normalized(&self, range: ValueRange) -> Vec<f64> {
+        let finite = || {
+            self.data
+                .iter()
+                .map(|v| v.to_f64())
+                .filter(|v| v.is_finite())
+        };
+        let (lo, hi) = match range {
+            ValueRange::Auto => (
+                finite().fold(f64::INFINITY, f64::min),
+                finite().fold(f64::NEG_INFINITY, f64::max),
+            ),
+            ValueRange::Symmetric => {
+                let m = finite().map(f64::abs).fold(0.0, f64::max);
+                (-m, m)
+            }
+            ValueRange::Fixed(lo, hi) => (lo, hi),
+        };
+
+        self.data
+            .iter()
+            .map(|v| {
+                let v = v.to_f64();
+                if v.is_nan() {
+                    v
+                } else if hi > lo {
+                    ((v - lo) / (hi - lo)).clamp(0.0, 1.0)
+                } else {
+                    0.5
+                }
+            })
+            .collect()
+    }
+
+    /// Colors of the cells row by row.
+    fn colors(&self, options: &RenderOptions) -> Vec<[u8; 3]> {
+        self.normalized(options.range)
+            .into_iter()
+            .map(|t| {
+                if t.is_nan() {
+                    NAN_COLOR
+                } else {
+                    options.colormap.color(t)
+                }
+            })
+            .collect()
+    }
+
+    /// Render the matrix as a heatmap for terminals supporting 24-bit ANSI colors,
+    /// two characters per cell so that cells are roughly square.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = special::hilbert(8);
+    /// println!("{}", mat.heatmap(&RenderOptions::default()));
+    /// ```
+    pub fn heatmap(&self, options: &RenderOptions) -> String {
+        let mut out = String::new();
+        for row in self.colors(options).chunks(self.cols) {
+            for [r, g, b] in row {
+                out.push_str(&format!("\x1b[48;2;{};{};{}m  ", r, g, b));
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    /// Write the matrix as an SVG image, one square of `cell_size` pixels per cell.
+    /// Cells which are not a number are black.
+    pub fn write_svg<W: Write>(&self, mut writer: W, options: &RenderOptions) -> io::Result<()> {
+        let size = options.cell_size;
+        writeln!(
+            writer,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">",
+            self.cols * size,
+            self.rows * size
+        )?;
+        for (i, [r, g, b]) in self.colors(options).into_iter().enumerate() {
+            writeln!(
+                writer,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>",
+                (i % self.cols) * size,
+                (i / self.cols) * size,
+                size,
+                size,
+                r,
+                g,
+                b
+            )?;
+        }
+        writeln!(writer, "</svg>")?;
+        writer.flush()
+    }
+
+    /// Write the matrix as a binary PGM (grayscale) image, ignoring the colormap.
+    /// Cells which are not a number are black.
+    pub fn write_pgm<W: Write>(&self, writer: W, options: &RenderOptions) -> io::Result<()> {
+        let levels: Vec<u8> = self
+            .normalized(options.range)
+            .into_iter()
+            .map(|t| if t.is_nan() { 0 } else { gray(t) })
+            .collect();
+        self.write_netpbm(writer, "P5", &levels, 1, options.cell_size)
+    }
+
+    /// Write the matrix as a binary PPM (color) image.
+    /// Cells which are not a number are black.
+    ///
+    /// # Examples
+    /// ```
+    /// let options = RenderOptions {
+    ///     colormap: Colormap::Diverging,
+    ///     range: ValueRange::Symmetric,
+    ///     cell_size: 4,
+    /// };
+    /// mat.write_ppm(std::fs::File::create("mat.ppm")?, &options)?;
+    /// ```
+    pub fn write_ppm<W: Write>(&self, writer: W, options: &RenderOptions) -> io::Result<()> {
+        let colors: Vec<u8> = self.colors(options).concat();
+        self.write_netpbm(writer, "P6", &colors, 3, options.cell_size)
+    }
+
+    /// Write a binary Netpbm image from the pixels of each cell, `channels` bytes each.
+    fn write_netpbm<W: Write>(
+        &self,
+        mut writer: W,
+        magic: &str,
+        pixels: &[u8],
+        channels: usize,
+        size: usize,
+    ) -> io::Result<()> {
+        write!(
+            writer,
+            "{}\n{} {}\n255\n",
+            magic,
+            self.cols * size,
+            self.rows * size
+        )?;
+
+        let mut line = Vec::new();
+        for row in pixels.chunks(self.cols * channels) {
+            line.clear();
+            for pixel in row.chunks(channels) {
+                for _ in 0..size {
+                    line.extend_from_slice(pixel);
+                }
+            }
+            for _ in 0..size {
+                writer.write_all(&line)?;
+            }
+        }
+        writer.flush()
+    }
+}
+
+impl<T: Ring> Matrix<T> {
+    /// Render the sparsity pattern of the matrix as text,
+    /// with `●` for non-zero cells and `·` for zero cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 3, vec![1, 0, 0, 0, 2, 3]);
+    ///
+    /// assert_eq!(mat.spy(), "● · ·\n· ● ●\n");
+    /// ```
+    pub fn spy(&self) -> String {
+        let zero = T::zero();
+        let mut out = String::new();
+        for row in self.data.chunks(self.cols) {
+            let marks: Vec<&str> = row
+                .iter()
+                .map(|v| if *v == zero { "·" } else { "●" })
+                .collect();
+            out.push_str(&marks.join(" "));
+            out.push('\n');
+        }
+        out
+    }
+}
@@ -0,0 +1,255 @@
+use super::Matrix;
+use std::mem;
+
+// Rows are contiguous in `data`, so they are inserted and removed in place,
+// while columns need a single pass moving every cell to a new buffer
+
+impl<T> Matrix<T> {
+    /// Append a row to the bottom of the matrix.
+    /// The iterator can be infinite, this method only consumes `cols` values from it.
+    ///
+    /// # Panics
+    /// Panics if the iterator does not have `cols` values
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(1, 2, vec![1, 2]);
+    /// mat.push_row(vec![3, 4]);
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 2, 1..));
+    /// ```
+    pub fn push_row(&mut self, values: impl IntoIterator<Item = T>) {
+        self.insert_row(self.rows, values);
+    }
+
+    /// Append a column to the right of the matrix.
+    /// The iterator can be infinite, this method only consumes `rows` values from it.
+    ///
+    /// # Panics
+    /// Panics if the iterator does not have `rows` values
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(2, 1, vec![1, 3]);
+    /// mat.push_col(vec![2, 4]);
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 2, 1..));
+    /// ```
+    pub fn push_col(&mut self, values: impl IntoIterator<Item = T>) {
+        self.insert_col(self.cols, values);
+    }
+
+    /// Insert a row at position `row`, shifting the following rows down.
+    /// The iterator can be infinite, this method only consumes `cols` values from it.
+    ///
+    /// # Panics
+    /// Panics if `row > rows`.
+    /// Panics if the iterator does not have `cols` values
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(2, 2, vec![1, 2, 5, 6]);
+    /// mat.insert_row(1, 3..);
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(3, 2, 1..));
+    /// ```
+    pub fn insert_row(&mut self, row: usize, values: impl IntoIterator<Item = T>) {
+        assert!(row <= self.rows);
+
+        let values: Vec<T> = values.into_iter().take(self.cols).collect();
+        assert_eq!(values.len(), self.cols);
+
+        let at = row * self.cols;
+        self.data.splice(at..at, values);
+        self.rows += 1;
+    }
+
+    /// Insert a column at position `col`, shifting the following columns right.
+    /// The iterator can be infinite, this method only consumes `rows` values from it.
+    ///
+    /// # Panics
+    /// Panics if `col > cols`.
+    /// Panics if the iterator does not have `rows` values
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(2, 2, vec![1, 3, 4, 6]);
+    /// mat.insert_col(1, vec![2, 5]);
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 3, 1..));
+    /// ```
+    pub fn insert_col(&mut self, col: usize, values: impl IntoIterator<Item = T>) {
+        assert!(col <= self.cols);
+
+        let values: Vec<T> = values.into_iter().take(self.rows).collect();
+        assert_eq!(values.len(), self.rows);
+
+        let mut data = Vec::with_capacity(self.rows * (self.cols + 1));
+        let mut old = mem::take(&mut self.data).into_iter();
+        for value in values {
+            data.extend(old.by_ref().take(col));
+            data.push(value);
+            data.extend(old.by_ref().take(self.cols - col));
+        }
+
+        self.data = data;
+        self.cols += 1;
+    }
+
+    /// Remove the row at position `row` and return its cells,
+    /// shifting the following rows up.
+    ///
+    /// # Panics
+    /// Panics if `row >= rows`.
+    /// Panics if the matrix has a single row, as it cannot be empty
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(3, 2, 1..);
+    ///
+    /// assert_eq!(mat.remove_row(1), vec![3, 4]);
+    /// assert_eq!(mat, Matrix::from_iter(2, 2, vec![1, 2, 5, 6]));
+    /// ```
+    pub fn remove_row(&mut self, row: usize) -> Vec<T> {
+        assert!(row < self.rows);
+        assert!(self.rows > 1);
+
+        self.rows -= 1;
+        self.data
+            .drain(row * self.cols..(row + 1) * self.cols)
+            .collect()
+    }
+
+    /// Remove the column at position `col` and return its cells,
+    /// shifting the following columns left.
+    ///
+    /// # Panics
+    /// Panics if `col >= cols`.
+    /// Panics if the matrix has a single column, as it cannot be empty
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    ///
+    /// assert_eq!(mat.remove_col(0), vec![1, 4]);
+    /// assert_eq!(mat, Matrix::from_iter(2, 2, vec![2, 3, 5, 6]));
+    /// ```
+    pub fn remove_col(&mut self, col: usize) -> Vec<T> {
+        assert!(col < self.cols);
+        assert!(self.cols > 1);
+
+        let mut removed = Vec::with_capacity(self.rows);
+        let mut data = Vec::with_capacity(self.rows * (self.cols - 1));
+        for (i, value) in mem::take(&mut self.data).into_iter().enumerate() {
+            if i % self.cols == col {
+                removed.push(value);
+            } else {
+                data.push(value);
+            }
+        }
+
+        self.data = data;
+        self.cols -= 1;
+        removed
+    }
+
+    /// Resize the matrix to `rows * cols`, keeping the cells at the same positions.
+    /// New cells are set to `fill`, and the cells out of the new shape are dropped.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are equal to `0`
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    /// mat.resize(3, 1, 0);
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(3, 1, vec![1, 3, 0]));
+    /// ```
+    pub fn resize(&mut self, rows: usize, cols: usize, fill: T)
+    where
+        T: Clone,
+    {
+        assert!(rows > 0 && cols > 0);
+
+        // Drop the extra rows first, so that they are not reshuffled
+        self.rows = self.rows.min(rows);
+        self.data.truncate(self.rows * self.cols);
+
+        if cols != self.cols {
+            let kept = self.cols.min(cols);
+            let mut data = Vec::with_capacity(rows * cols);
+            let mut old = mem::take(&mut self.data).into_iter();
+            for _ in 0..self.rows {
+                let mut row = old.by_ref().take(self.cols);
+                data.extend(row.by_ref().take(kept));
+                row.for_each(drop);
+                data.resize(data.len() + cols - kept, fill.clone());
+            }
+            self.data = data;
+            self.cols = cols;
+        }
+
+        self.data.resize(rows * cols, fill);
+        self.rows = rows;
+    }
+
+    /// Shrink the matrix to at most `rows * cols`, dropping the cells out of the new shape.
+    /// Dimensions which are already smaller are left unchanged.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are equal to `0`
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(3, 3, 1..);
+    /// mat.truncate(2, 5);
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(2, 3, 1..));
+    /// ```
+    pub fn truncate(&mut self, rows: usize, cols: usize) {
+        assert!(rows > 0 && cols > 0);
+
+        self.rows = self.rows.min(rows);
+        self.data.truncate(self.rows * self.cols);
+
+        if cols < self.cols {
+            let old_cols = self.cols;
+            let mut i = 0;
+            self.data.retain(|_| {
+                let keep = i % old_cols < cols;
+                i += 1;
+                keep
+            });
+            self.cols = cols;
+        }
+    }
+
+    /// Keep only the rows for which the predicate returns `true`, in the same order.
+    /// The predicate is called once per row with the cells of the row.
+    ///
+    /// # Panics
+    /// Panics if no row is kept, as the matrix cannot be empty
+    ///
+    /// # Examples
+    /// ```
+    /// let mut mat: Matrix<i32> = Matrix::from_iter(3, 2, vec![1, -2, 3, 4, -5, 6]);
+    /// mat.retain_rows(|row| row.iter().all(|&v| v > 0));
+    ///
+    /// assert_eq!(mat, Matrix::from_iter(1, 2, vec![3, 4]));
+    /// ```
+    pub fn retain_rows<F: FnMut(&[T]) -> bool>(&mut self, pred: F) {
+        let keep: Vec<bool> = self.data.chunks(self.cols).map(pred).collect();
+        let rows = keep.iter().filter(|&&k| k).count();
+        assert!(rows > 0);
+
+        let cols = self.cols;
+        let mut i = 0;
+        self.data.retain(|_| {
+            let k = keep[i / cols];
+            i += 1;
+            k
+        });
+        self.rows = rows;
+    }
+}
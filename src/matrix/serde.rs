@@ -0,0 +1,106 @@
+use super::Matrix;
+use ::serde::de::{Deserialize, Deserializer, Error};
+use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// Serialized as a `{rows, cols, data}` struct, `data` holding the cells row by row.
+impl<T: Serialize> Serialize for Matrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("rows", &self.rows)?;
+        state.serialize_field("cols", &self.cols)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+
+#[derive(::serde::Deserialize)]
+#[serde(crate = "::serde", rename = "Matrix")]
+struct Raw<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+/// Fails if the matrix is empty, or if `data` does not hold `rows * cols` cells.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Matrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Raw { rows, cols, data } = Raw::deserialize(deserializer)?;
+
+        if rows == 0 || cols == 0 {
+            return Err(D::Error::custom(format!(
+                "invalid matrix size {}x{}",
+                rows, cols
+            )));
+        }
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(D::Error::custom(format!(
+                "expected {} cells for a {}x{} matrix, found {}",
+                rows.saturating_mul(cols),
+                rows,
+                cols,
+                data.len()
+            )));
+        }
+
+        Ok(Matrix { rows, cols, data })
+    }
+}
+
+/// Serialize a matrix as an array of rows, e.g. `[[1, 2], [3, 4]]`,
+/// for use with `#[serde(with = "simple_matrix::nested_rows")]`.
+///
+/// Deserialization fails if there are no rows, if the rows are empty,
+/// or if they do not have the same length.
+///
+/// # Examples
+/// ```
+/// #[derive(Serialize, Deserialize)]
+/// struct Layer {
+///     #[serde(with = "simple_matrix::nested_rows")]
+///     weights: Matrix<f64>,
+/// }
+/// ```
+pub mod nested_rows {
+    use super::Matrix;
+    use ::serde::de::{Deserialize, Deserializer, Error};
+    use ::serde::ser::{Serialize, Serializer};
+
+    /// Serialize the matrix as an array of rows.
+    pub fn serialize<T, S>(mat: &Matrix<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(mat.data.chunks(mat.cols))
+    }
+
+    /// Deserialize a matrix from an array of rows.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Matrix<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let nested: Vec<Vec<T>> = Vec::deserialize(deserializer)?;
+
+        let rows = nested.len();
+        let cols = nested.first().map_or(0, Vec::len);
+        if cols == 0 {
+            return Err(D::Error::custom("expected a non-empty array of rows"));
+        }
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for (i, row) in nested.into_iter().enumerate() {
+            if row.len() != cols {
+                return Err(D::Error::custom(format!(
+                    "row {} has {} cells, expected {}",
+                    i,
+                    row.len(),
+                    cols
+                )));
+            }
+            data.extend(row);
+        }
+
+        Ok(Matrix { rows, cols, data })
+    }
+}
@@ -0,0 +1,856 @@
+//! Sparse matrix storage formats.
+//!
+//! Compressed matrices never store zeros explicitly:
+//! stored values are non-zero, and their indices are sorted and unique within each row (CSR)
+//! or column (CSC).
+
+use super::iterative::LinearOperator;
+use super::{Matrix, Ring};
+use std::ops::{Add, Mul};
+
+/// A sparse matrix in coordinate format, storing a list of `(row, col, value)` triplets.
+/// Triplets can be pushed in any order, and duplicates are summed together.
+/// It is mostly useful to build a matrix, before converting it to `CsrMatrix` or `CscMatrix`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    row_indices: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+/// A sparse matrix in compressed sparse row format.
+/// Efficient for row access, additions, and products with a matrix on the right.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CsrMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Compressed<T>,
+}
+
+/// A sparse matrix in compressed sparse column format.
+/// Efficient for column access, additions, and products with a matrix on the right.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CscMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Compressed<T>,
+}
+
+impl<T> CooMatrix<T> {
+    /// Constructs a new, empty sparse matrix.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are equal to `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut coo: CooMatrix<f64> = CooMatrix::new(3, 3);
+    /// coo.push(0, 0, 1.0);
+    /// coo.push(2, 1, -1.0);
+    ///
+    /// assert_eq!(coo.nnz(), 2);
+    /// ```
+    pub fn new(rows: usize, cols: usize) -> CooMatrix<T> {
+        assert!(rows > 0 && cols > 0);
+
+        CooMatrix {
+            rows,
+            cols,
+            row_indices: Vec::new(),
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the amount of columns the matrix has.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored triplets, including duplicates and zeros.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Add a `(row, col, value)` triplet to the matrix.
+    ///
+    /// # Panics
+    /// Panics if `row` or `col` are out of bounds.
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        assert!(row < self.rows && col < self.cols);
+
+        self.row_indices.push(row);
+        self.col_indices.push(col);
+        self.values.push(value);
+    }
+
+    /// Returns an iterator over the stored `(row, col, value)` triplets, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.row_indices
+            .iter()
+            .zip(&self.col_indices)
+            .zip(&self.values)
+            .map(|((&row, &col), value)| (row, col, value))
+    }
+
+    /// Construct the transpose of the matrix.
+    pub fn transpose(&self) -> CooMatrix<T>
+    where
+        T: Clone,
+    {
+        CooMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_indices: self.col_indices.clone(),
+            col_indices: self.row_indices.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<T: Ring> CooMatrix<T> {
+    /// Convert the matrix to the compressed sparse row format,
+    /// summing duplicates and dropping zeros.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: Compressed::from_triplets(
+                self.rows,
+                self.iter().map(|(row, col, v)| (row, col, v.clone())),
+            ),
+        }
+    }
+
+    /// Convert the matrix to the compressed sparse column format,
+    /// summing duplicates and dropping zeros.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: Compressed::from_triplets(
+                self.cols,
+                self.iter().map(|(row, col, v)| (col, row, v.clone())),
+            ),
+        }
+    }
+
+    /// Convert the matrix to a dense `Matrix`, summing duplicates.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data = vec![T::zero(); self.rows * self.cols];
+        for (row, col, v) in self.iter() {
+            let cell = &mut data[col + row * self.cols];
+            *cell = cell.clone() + v.clone();
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+}
+
+impl<T> CsrMatrix<T> {
+    /// Constructs a sparse matrix from its raw compressed sparse row arrays.
+    /// The column indices and values of row `i` are stored
+    /// in the range `row_offsets[i]..row_offsets[i + 1]` of `col_indices` and `values`.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are equal to `0`.
+    /// Panics if `row_offsets` does not have `rows + 1` non-decreasing values
+    /// starting at `0` and ending at `values.len()`,
+    /// or if the column indices of a row are not strictly increasing and in bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// // [[1, 0, 2],
+    /// //  [0, 0, 3]]
+    /// let csr = CsrMatrix::from_parts(2, 3, vec![0, 2, 3], vec![0, 2, 2], vec![1, 2, 3]);
+    ///
+    /// assert_eq!(csr.get_row(1).unwrap().collect::<Vec<_>>(), vec![(2, &3)]);
+    /// ```
+    pub fn from_parts(
+        rows: usize,
+        cols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> CsrMatrix<T> {
+        assert!(rows > 0 && cols > 0);
+
+        CsrMatrix {
+            rows,
+            cols,
+            data: Compressed::from_parts(rows, cols, row_offsets, col_indices, values),
+        }
+    }
+
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the amount of columns the matrix has.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored non-zero values.
+    pub fn nnz(&self) -> usize {
+        self.data.values.len()
+    }
+
+    /// Returns the row offsets, of length `rows + 1`.
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.data.offsets
+    }
+
+    /// Returns the column index of every stored value.
+    pub fn col_indices(&self) -> &[usize] {
+        &self.data.indices
+    }
+
+    /// Returns the stored values, row by row.
+    pub fn values(&self) -> &[T] {
+        &self.data.values
+    }
+
+    /// Returns an iterator over the `(col, value)` non-zeros of a row, by increasing column.
+    /// Returns `None` if `row` is out of bounds.
+    pub fn get_row(&self, row: usize) -> Option<impl Iterator<Item = (usize, &T)>> {
+        if row < self.rows {
+            Some(self.data.lane(row))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the `(row, col, value)` non-zeros, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.data.iter()
+    }
+
+    /// Construct the transpose of the matrix.
+    pub fn transpose(&self) -> CsrMatrix<T>
+    where
+        T: Clone,
+    {
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            data: self.data.transpose(self.cols),
+        }
+    }
+
+    /// Convert the matrix to the compressed sparse column format.
+    pub fn to_csc(&self) -> CscMatrix<T>
+    where
+        T: Clone,
+    {
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.transpose(self.cols),
+        }
+    }
+
+    /// Convert the matrix to the coordinate format.
+    pub fn to_coo(&self) -> CooMatrix<T>
+    where
+        T: Clone,
+    {
+        let mut coo = CooMatrix::new(self.rows, self.cols);
+        for (row, col, v) in self.iter() {
+            coo.push(row, col, v.clone());
+        }
+        coo
+    }
+}
+
+impl<T: Ring> CsrMatrix<T> {
+    /// Convert the matrix to a dense `Matrix`.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data = vec![T::zero(); self.rows * self.cols];
+        for (row, col, v) in self.iter() {
+            data[col + row * self.cols] = v.clone();
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+}
+
+impl<T> CscMatrix<T> {
+    /// Constructs a sparse matrix from its raw compressed sparse column arrays.
+    /// The row indices and values of column `j` are stored
+    /// in the range `col_offsets[j]..col_offsets[j + 1]` of `row_indices` and `values`.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are equal to `0`.
+    /// Panics if `col_offsets` does not have `cols + 1` non-decreasing values
+    /// starting at `0` and ending at `values.len()`,
+    /// or if the row indices of a column are not strictly increasing and in bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// // [[1, 0, 2],
+    /// //  [0, 0, 3]]
+    /// let csc = CscMatrix::from_parts(2, 3, vec![0, 1, 1, 3], vec![0, 0, 1], vec![1, 2, 3]);
+    ///
+    /// assert_eq!(csc.get_col(2).unwrap().collect::<Vec<_>>(), vec![(0, &2), (1, &3)]);
+    /// ```
+    pub fn from_parts(
+        rows: usize,
+        cols: usize,
+        col_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> CscMatrix<T> {
+        assert!(rows > 0 && cols > 0);
+
+        CscMatrix {
+            rows,
+            cols,
+            data: Compressed::from_parts(cols, rows, col_offsets, row_indices, values),
+        }
+    }
+
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the amount of columns the matrix has.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored non-zero values.
+    pub fn nnz(&self) -> usize {
+        self.data.values.len()
+    }
+
+    /// Returns the column offsets, of length `cols + 1`.
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.data.offsets
+    }
+
+    /// Returns the row index of every stored value.
+    pub fn row_indices(&self) -> &[usize] {
+        &self.data.indices
+    }
+
+    /// Returns the stored values, column by column.
+    pub fn values(&self) -> &[T] {
+        &self.data.values
+    }
+
+    /// Returns an iterator over the `(row, value)` non-zeros of a column, by increasing row.
+    /// Returns `None` if `col` is out of bounds.
+    pub fn get_col(&self, col: usize) -> Option<impl Iterator<Item = (usize, &T)>> {
+        if col < self.cols {
+            Some(self.data.lane(col))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the `(row, col, value)` non-zeros, column by column.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.data.iter().map(|(col, row, v)| (row, col, v))
+    }
+
+    /// Construct the transpose of the matrix.
+    pub fn transpose(&self) -> CscMatrix<T>
+    where
+        T: Clone,
+    {
+        CscMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            data: self.data.transpose(self.rows),
+        }
+    }
+
+    /// Convert the matrix to the compressed sparse row format.
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where
+        T: Clone,
+    {
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.transpose(self.rows),
+        }
+    }
+
+    /// Convert the matrix to the coordinate format.
+    pub fn to_coo(&self) -> CooMatrix<T>
+    where
+        T: Clone,
+    {
+        let mut coo = CooMatrix::new(self.rows, self.cols);
+        for (row, col, v) in self.iter() {
+            coo.push(row, col, v.clone());
+        }
+        coo
+    }
+}
+
+impl<T: Ring> CscMatrix<T> {
+    /// Convert the matrix to a dense `Matrix`.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data = vec![T::zero(); self.rows * self.cols];
+        for (row, col, v) in self.iter() {
+            data[col + row * self.cols] = v.clone();
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+}
+
+impl<'a, T: Ring> From<&'a Matrix<T>> for CooMatrix<T> {
+    fn from(mat: &'a Matrix<T>) -> CooMatrix<T> {
+        let mut coo = CooMatrix::new(mat.rows, mat.cols);
+        for (i, v) in mat.data.iter().enumerate() {
+            if *v != T::zero() {
+                coo.push(i / mat.cols, i % mat.cols, v.clone());
+            }
+        }
+        coo
+    }
+}
+
+impl<'a, T: Ring> From<&'a Matrix<T>> for CsrMatrix<T> {
+    fn from(mat: &'a Matrix<T>) -> CsrMatrix<T> {
+        CooMatrix::from(mat).to_csr()
+    }
+}
+
+impl<'a, T: Ring> From<&'a Matrix<T>> for CscMatrix<T> {
+    fn from(mat: &'a Matrix<T>) -> CscMatrix<T> {
+        CooMatrix::from(mat).to_csc()
+    }
+}
+
+impl<T: Ring> Add<&CooMatrix<T>> for &CooMatrix<T> {
+    type Output = CooMatrix<T>;
+
+    fn add(self, rhs: &CooMatrix<T>) -> Self::Output {
+        assert!(self.rows == rhs.rows);
+        assert!(self.cols == rhs.cols);
+
+        // Duplicates are summed on conversion, so both lists can simply be merged
+        let mut coo = self.clone();
+        coo.row_indices.extend_from_slice(&rhs.row_indices);
+        coo.col_indices.extend_from_slice(&rhs.col_indices);
+        coo.values.extend_from_slice(&rhs.values);
+        coo
+    }
+}
+
+impl<T: Ring> Add<&CsrMatrix<T>> for &CsrMatrix<T> {
+    type Output = CsrMatrix<T>;
+
+    fn add(self, rhs: &CsrMatrix<T>) -> Self::Output {
+        assert!(self.rows == rhs.rows);
+        assert!(self.cols == rhs.cols);
+
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.add(&rhs.data),
+        }
+    }
+}
+
+impl<T: Ring> Add<&CscMatrix<T>> for &CscMatrix<T> {
+    type Output = CscMatrix<T>;
+
+    fn add(self, rhs: &CscMatrix<T>) -> Self::Output {
+        assert!(self.rows == rhs.rows);
+        assert!(self.cols == rhs.cols);
+
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.add(&rhs.data),
+        }
+    }
+}
+
+impl<T: Ring> Mul<&CsrMatrix<T>> for &CsrMatrix<T> {
+    type Output = CsrMatrix<T>;
+
+    fn mul(self, rhs: &CsrMatrix<T>) -> Self::Output {
+        assert!(self.cols == rhs.rows);
+
+        CsrMatrix {
+            rows: self.rows,
+            cols: rhs.cols,
+            data: self.data.mul(&rhs.data, rhs.cols),
+        }
+    }
+}
+
+impl<T: Ring> Mul<&CscMatrix<T>> for &CscMatrix<T> {
+    type Output = CscMatrix<T>;
+
+    fn mul(self, rhs: &CscMatrix<T>) -> Self::Output {
+        assert!(self.cols == rhs.rows);
+
+        // The CSC arrays of `A` are the CSR arrays of `A^T`, and `(A * B)^T = B^T * A^T`
+        CscMatrix {
+            rows: self.rows,
+            cols: rhs.cols,
+            data: rhs.data.mul(&self.data, self.rows),
+        }
+    }
+}
+
+impl<T: Ring> Mul<&Matrix<T>> for &CsrMatrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        assert!(self.cols == rhs.rows);
+
+        let cols = rhs.cols;
+        let mut data = vec![T::zero(); self.rows * cols];
+        for (row, k, a) in self.iter() {
+            for (out, b) in data[row * cols..(row + 1) * cols]
+                .iter_mut()
+                .zip(&rhs.data[k * cols..(k + 1) * cols])
+            {
+                *out = out.clone() + a.clone() * b.clone();
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols,
+            data,
+        }
+    }
+}
+
+impl<T: Ring> Mul<&Matrix<T>> for &CscMatrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        assert!(self.cols == rhs.rows);
+
+        let cols = rhs.cols;
+        let mut data = vec![T::zero(); self.rows * cols];
+        for (row, k, a) in self.iter() {
+            for (out, b) in data[row * cols..(row + 1) * cols]
+                .iter_mut()
+                .zip(&rhs.data[k * cols..(k + 1) * cols])
+            {
+                *out = out.clone() + a.clone() * b.clone();
+            }
+        }
+
+        Matrix {
+            rows: self.rows,
+            cols,
+            data,
+        }
+    }
+}
+
+impl<T: Ring> LinearOperator<T> for CsrMatrix<T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn mul_vec(&self, x: &[T], y: &mut [T]) {
+        assert!(x.len() == self.cols);
+        assert!(y.len() == self.rows);
+
+        for (row, y) in y.iter_mut().enumerate() {
+            *y = self
+                .data
+                .lane(row)
+                .fold(T::zero(), |acc, (col, v)| acc + v.clone() * x[col].clone());
+        }
+    }
+}
+
+impl<T: Ring> LinearOperator<T> for CscMatrix<T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn mul_vec(&self, x: &[T], y: &mut [T]) {
+        assert!(x.len() == self.cols);
+        assert!(y.len() == self.rows);
+
+        y.iter_mut().for_each(|y| *y = T::zero());
+        for (row, col, v) in self.iter() {
+            y[row] = y[row].clone() + v.clone() * x[col].clone();
+        }
+    }
+}
+
+/// Compressed storage shared by the CSR and CSC formats.
+/// For CSR the outer dimension is the rows, and the inner one the columns;
+/// for CSC it is the other way around.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Compressed<T> {
+    /// Lane `k` is stored in the range `offsets[k]..offsets[k + 1]`.
+    offsets: Vec<usize>,
+    /// Inner index of every stored value.
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> Compressed<T> {
+    fn from_parts(
+        outer: usize,
+        inner: usize,
+        offsets: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Compressed<T> {
+        assert!(offsets.len() == outer + 1);
+        assert!(offsets[0] == 0 && offsets[outer] == values.len());
+        assert!(indices.len() == values.len());
+        assert!(offsets.windows(2).all(|w| w[0] <= w[1]));
+        for lane in offsets.windows(2) {
+            let lane = &indices[lane[0]..lane[1]];
+            assert!(lane.windows(2).all(|w| w[0] < w[1]));
+            assert!(lane.iter().all(|&i| i < inner));
+        }
+
+        Compressed {
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    /// Compress `(outer, inner, value)` triplets,
+    /// summing duplicates and dropping zeros.
+    fn from_triplets(
+        outer: usize,
+        triplets: impl Iterator<Item = (usize, usize, T)>,
+    ) -> Compressed<T>
+    where
+        T: Ring,
+    {
+        let mut triplets: Vec<_> = triplets.collect();
+        triplets.sort_by_key(|&(o, i, _)| (o, i));
+
+        let mut offsets = vec![0; outer + 1];
+        let mut indices: Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+        for (o, i, v) in triplets {
+            if last == Some((o, i)) {
+                let acc = values.pop().unwrap();
+                values.push(acc + v);
+            } else {
+                Self::drop_trailing_zero(&mut offsets, &mut indices, &mut values, last);
+                offsets[o + 1] += 1;
+                indices.push(i);
+                values.push(v);
+                last = Some((o, i));
+            }
+        }
+        Self::drop_trailing_zero(&mut offsets, &mut indices, &mut values, last);
+
+        for k in 0..outer {
+            offsets[k + 1] += offsets[k];
+        }
+
+        Compressed {
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    /// Remove the last value pushed by `from_triplets` at `last` if it summed up to zero.
+    fn drop_trailing_zero(
+        counts: &mut [usize],
+        indices: &mut Vec<usize>,
+        values: &mut Vec<T>,
+        last: Option<(usize, usize)>,
+    ) where
+        T: Ring,
+    {
+        if let Some((o, _)) = last {
+            if values.last() == Some(&T::zero()) {
+                values.pop();
+                indices.pop();
+                counts[o + 1] -= 1;
+            }
+        }
+    }
+
+    fn lane(&self, k: usize) -> impl Iterator<Item = (usize, &T)> {
+        let range = self.offsets[k]..self.offsets[k + 1];
+        self.indices[range.clone()]
+            .iter()
+            .cloned()
+            .zip(&self.values[range])
+    }
+
+    /// Iterate over the `(outer, inner, value)` triplets.
+    fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.offsets.windows(2).enumerate().flat_map(move |(k, w)| {
+            self.indices[w[0]..w[1]]
+                .iter()
+                .zip(&self.values[w[0]..w[1]])
+                .map(move |(&i, v)| (k, i, v))
+        })
+    }
+
+    /// Swap the outer and inner dimensions, with a counting sort.
+    fn transpose(&self, inner: usize) -> Compressed<T>
+    where
+        T: Clone,
+    {
+        let mut offsets = vec![0; inner + 1];
+        for &i in &self.indices {
+            offsets[i + 1] += 1;
+        }
+        for i in 0..inner {
+            offsets[i + 1] += offsets[i];
+        }
+
+        // Visiting the outer lanes in order keeps the new inner indices sorted
+        let mut next = offsets.clone();
+        let mut slots: Vec<Option<(usize, T)>> = vec![None; self.values.len()];
+        for (o, i, v) in self.iter() {
+            slots[next[i]] = Some((o, v.clone()));
+            next[i] += 1;
+        }
+        let (indices, values) = slots.into_iter().map(Option::unwrap).unzip();
+
+        Compressed {
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    /// Merge the lanes of two compressed matrices of the same shape, adding their values.
+    fn add(&self, rhs: &Compressed<T>) -> Compressed<T>
+    where
+        T: Ring,
+    {
+        let mut offsets = Vec::with_capacity(self.offsets.len());
+        let mut indices = Vec::with_capacity(self.indices.len() + rhs.indices.len());
+        let mut values = Vec::with_capacity(self.indices.len() + rhs.indices.len());
+        offsets.push(0);
+
+        for k in 0..self.offsets.len() - 1 {
+            let mut a = self.lane(k).peekable();
+            let mut b = rhs.lane(k).peekable();
+            loop {
+                let (i, v) = match (a.peek(), b.peek()) {
+                    (Some(&(i, x)), Some(&(j, y))) if i == j => {
+                        a.next();
+                        b.next();
+                        (i, x.clone() + y.clone())
+                    }
+                    (Some(&(i, x)), Some(&(j, _))) if i < j => {
+                        a.next();
+                        (i, x.clone())
+                    }
+                    (_, Some(&(j, y))) => {
+                        b.next();
+                        (j, y.clone())
+                    }
+                    (Some(&(i, x)), None) => {
+                        a.next();
+                        (i, x.clone())
+                    }
+                    (None, None) => break,
+                };
+                if v != T::zero() {
+                    indices.push(i);
+                    values.push(v);
+                }
+            }
+            offsets.push(values.len());
+        }
+
+        Compressed {
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    /// Multiply two compressed matrices, seen as CSR, with Gustavson's algorithm.
+    /// `inner` is the inner dimension of `rhs`.
+    fn mul(&self, rhs: &Compressed<T>, inner: usize) -> Compressed<T>
+    where
+        T: Ring,
+    {
+        let mut offsets = Vec::with_capacity(self.offsets.len());
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        offsets.push(0);
+
+        // Dense accumulator for the current lane, along with its occupied indices
+        let mut acc: Vec<Option<T>> = vec![None; inner];
+        let mut occupied = Vec::new();
+
+        for k in 0..self.offsets.len() - 1 {
+            for (j, a) in self.lane(k) {
+                for (i, b) in rhs.lane(j) {
+                    let prod = a.clone() * b.clone();
+                    acc[i] = Some(match acc[i].take() {
+                        Some(sum) => sum + prod,
+                        None => {
+                            occupied.push(i);
+                            prod
+                        }
+                    });
+                }
+            }
+
+            occupied.sort_unstable();
+            for i in occupied.drain(..) {
+                let v = acc[i].take().unwrap();
+                if v != T::zero() {
+                    indices.push(i);
+                    values.push(v);
+                }
+            }
+            offsets.push(values.len());
+        }
+
+        Compressed {
+            offsets,
+            indices,
+            values,
+        }
+    }
+}
@@ -0,0 +1,276 @@
+/*!
+Constructors of well-known matrices, useful as test and model matrices.
+
+# Example
+```
+use simple_matrix::special;
+
+let mat: Matrix<i64> = special::pascal(4);
+
+assert_eq!(mat.determinant(), Some(1));
+```
+*/
+
+use super::{Matrix, Real, Ring};
+use std::ops::Div;
+
+/// Construct the Vandermonde matrix of the points `x`, with `cols` columns:
+/// row `i` is `[1, x[i], x[i]², …, x[i]^(cols - 1)]`.
+///
+/// # Panics
+/// Panics if `x` is empty or `cols` is equal to `0`.
+///
+/// # Examples
+/// ```
+/// let mat = special::vandermonde(&[1, 2, 3], 3);
+///
+/// assert_eq!(mat, Matrix::from_iter(3, 3, vec![1, 1, 1, 1, 2, 4, 1, 3, 9]));
+/// ```
+pub fn vandermonde<T: Ring>(x: &[T], cols: usize) -> Matrix<T> {
+    Matrix::from_iter(
+        x.len(),
+        cols,
+        x.iter().flat_map(|x| {
+            (0..cols).scan(T::one(), move |pow, _| {
+                let v = pow.clone();
+                *pow = pow.clone() * x.clone();
+                Some(v)
+            })
+        }),
+    )
+}
+
+/// Construct the Toeplitz matrix with the given first column and first row,
+/// which is constant along each diagonal.
+/// The first value of `first_row` is ignored, the diagonal being `first_col[0]`.
+///
+/// # Panics
+/// Panics if either `first_col` or `first_row` is empty.
+///
+/// # Examples
+/// ```
+/// let mat = special::toeplitz(&[1, 2, 3], &[1, 4]);
+///
+/// assert_eq!(mat, Matrix::from_iter(3, 2, vec![1, 4, 2, 1, 3, 2]));
+/// ```
+pub fn toeplitz<T: Clone>(first_col: &[T], first_row: &[T]) -> Matrix<T> {
+    let cols = first_row.len();
+    Matrix::from_iter(
+        first_col.len(),
+        cols,
+        (0..first_col.len() * cols).map(|i| {
+            let (row, col) = (i / cols, i % cols);
+            if row >= col {
+                first_col[row - col].clone()
+            } else {
+                first_row[col - row].clone()
+            }
+        }),
+    )
+}
+
+/// Construct the circulant matrix with the given first column:
+/// each column is the previous one, rotated down by one cell.
+///
+/// # Panics
+/// Panics if `first_col` is empty.
+///
+/// # Examples
+/// ```
+/// let mat = special::circulant(&[1, 2, 3]);
+///
+/// assert_eq!(mat, Matrix::from_iter(3, 3, vec![1, 3, 2, 2, 1, 3, 3, 2, 1]));
+/// ```
+pub fn circulant<T: Clone>(first_col: &[T]) -> Matrix<T> {
+    let len = first_col.len();
+    Matrix::from_iter(
+        len,
+        len,
+        (0..len * len).map(|i| first_col[(len + i / len - i % len) % len].clone()),
+    )
+}
+
+/// Construct the Hankel matrix with the given first column and last row,
+/// which is constant along each anti-diagonal.
+/// The first value of `last_row` is ignored, the bottom-left cell being the last of `first_col`.
+///
+/// # Panics
+/// Panics if either `first_col` or `last_row` is empty.
+///
+/// # Examples
+/// ```
+/// let mat = special::hankel(&[1, 2, 3], &[3, 4]);
+///
+/// assert_eq!(mat, Matrix::from_iter(3, 2, vec![1, 2, 2, 3, 3, 4]));
+/// ```
+pub fn hankel<T: Clone>(first_col: &[T], last_row: &[T]) -> Matrix<T> {
+    let rows = first_col.len();
+    let cols = last_row.len();
+    Matrix::from_iter(
+        rows,
+        cols,
+        (0..rows * cols).map(|i| {
+            let k = i / cols + i % cols;
+            if k < rows {
+                first_col[k].clone()
+            } else {
+                last_row[k + 1 - rows].clone()
+            }
+        }),
+    )
+}
+
+/// Construct the *N*x*N* Hilbert matrix, with cells `1 / (row + col + 1)`.
+/// It is a classic example of a badly conditioned matrix.
+///
+/// # Panics
+/// Panics if `len` is equal to `0`.
+pub fn hilbert<T: Real>(len: usize) -> Matrix<T> {
+    Matrix::from_iter(
+        len,
+        len,
+        (0..len * len).map(|i| T::one() / T::from_f64((i / len + i % len + 1) as f64)),
+    )
+}
+
+/// Construct the *N*x*N* symmetric Pascal matrix,
+/// with cells the binomial coefficients `(row + col)! / (row! * col!)`.
+/// Its determinant is always `1`.
+///
+/// # Panics
+/// Panics if `len` is equal to `0`.
+///
+/// # Examples
+/// ```
+/// let mat: Matrix<u32> = special::pascal(3);
+///
+/// assert_eq!(mat, Matrix::from_iter(3, 3, vec![1, 1, 1, 1, 2, 3, 1, 3, 6]));
+/// ```
+pub fn pascal<T: Ring>(len: usize) -> Matrix<T> {
+    let mut mat = Matrix::from_iter(len, len, (0..).map(|_| T::one()));
+    for row in 1..len {
+        for col in 1..len {
+            let v = mat.data[col + (row - 1) * len].clone() + mat.data[col - 1 + row * len].clone();
+            mat.data[col + row * len] = v;
+        }
+    }
+    mat
+}
+
+/// Construct the companion matrix of a polynomial,
+/// whose eigenvalues are the roots of the polynomial.
+/// The coefficients are given from the highest degree down to the constant term,
+/// and are divided by the leading one, so integer polynomials should be monic.
+///
+/// # Panics
+/// Panics if there are less than two coefficients, or if the leading one is zero.
+///
+/// # Examples
+/// ```
+/// // x² - 3x + 2 = (x - 1)(x - 2)
+/// let mat = special::companion(&[1, -3, 2]);
+///
+/// assert_eq!(mat, Matrix::from_iter(2, 2, vec![3, -2, 1, 0]));
+/// ```
+pub fn companion<T>(coeffs: &[T]) -> Matrix<T>
+where
+    T: Ring + Div<Output = T>,
+{
+    assert!(coeffs.len() >= 2);
+    assert!(coeffs[0] != T::zero());
+
+    let len = coeffs.len() - 1;
+    let mut mat = Matrix::from_iter(len, len, (0..).map(|_| T::zero()));
+    for (cell, c) in mat.data.iter_mut().zip(&coeffs[1..]) {
+        *cell = T::zero() - c.clone() / coeffs[0].clone();
+    }
+    for i in 1..len {
+        mat.data[i - 1 + i * len] = T::one();
+    }
+    mat
+}
+
+/// Construct the Householder reflector `I - 2 * v * vᵀ / (vᵀ * v)`,
+/// the reflection across the hyperplane orthogonal to `v`.
+///
+/// # Panics
+/// Panics if `v` is empty or zero.
+///
+/// # Examples
+/// ```
+/// let mat = special::householder(&[1.0, 1.0]);
+///
+/// assert_eq!(mat, Matrix::from_iter(2, 2, vec![0.0, -1.0, -1.0, 0.0]));
+/// ```
+pub fn householder<T: Real>(v: &[T]) -> Matrix<T> {
+    let norm2 = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+    assert!(norm2 != T::zero());
+
+    let len = v.len();
+    let scale = T::from_f64(2.0) / norm2;
+    Matrix::from_iter(
+        len,
+        len,
+        (0..len * len).map(|i| {
+            let (row, col) = (i / len, i % len);
+            let id = if row == col { T::one() } else { T::zero() };
+            id - scale * v[row] * v[col]
+        }),
+    )
+}
+
+/// Construct the *N*x*N* Givens rotation of angle `theta` (in radians) in the `(i, j)` plane:
+/// the identity matrix, except for the cells
+/// `(i, i) = (j, j) = cos θ`, `(i, j) = sin θ` and `(j, i) = -sin θ`.
+///
+/// # Panics
+/// Panics if `i` or `j` is not smaller than `len`, or if `i == j`.
+///
+/// # Examples
+/// ```
+/// let mat: Matrix<f64> = special::givens(3, 0, 2, std::f64::consts::PI);
+///
+/// assert!(mat.approx_eq(&Matrix::from_iter(3, 3, vec![-1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0]), 1e-15, 0.0));
+/// ```
+pub fn givens<T: Real>(len: usize, i: usize, j: usize, theta: T) -> Matrix<T> {
+    assert!(i < len && j < len && i != j);
+
+    let (sin, cos) = theta.to_f64().sin_cos();
+    let mut mat = super::dense::identity(len);
+    mat.data[i + i * len] = T::from_f64(cos);
+    mat.data[j + j * len] = T::from_f64(cos);
+    mat.data[j + i * len] = T::from_f64(sin);
+    mat.data[i + j * len] = T::from_f64(-sin);
+    mat
+}
+
+/// Construct the block-diagonal matrix with the given blocks along its diagonal,
+/// and zeros elsewhere.
+/// The blocks do not need to be square.
+///
+/// # Panics
+/// Panics if `blocks` is empty.
+///
+/// # Examples
+/// ```
+/// let a = Matrix::from_iter(1, 2, vec![1, 2]);
+/// let b = Matrix::from_iter(1, 1, vec![3]);
+///
+/// assert_eq!(special::block_diagonal(&[a, b]), Matrix::from_iter(2, 3, vec![1, 2, 0, 0, 0, 3]));
+/// ```
+pub fn block_diagonal<T: Ring>(blocks: &[Matrix<T>]) -> Matrix<T> {
+    let rows = blocks.iter().map(|b| b.rows).sum();
+    let cols = blocks.iter().map(|b| b.cols).sum();
+    let mut mat = Matrix::from_iter(rows, cols, (0..).map(|_| T::zero()));
+
+    let (mut row, mut col) = (0, 0);
+    for block in blocks {
+        for (r, values) in block.data.chunks(block.cols).enumerate() {
+            let start = col + (row + r) * cols;
+            mat.data[start..start + block.cols].clone_from_slice(values);
+        }
+        row += block.rows;
+        col += block.cols;
+    }
+    mat
+}
@@ -0,0 +1,191 @@
+use super::Matrix;
+
+/// Axis along which `Matrix::concat` joins matrices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    /// Matrices are placed on top of each other, as with `Matrix::vstack`.
+    Rows,
+    /// Matrices are placed side by side, as with `Matrix::hstack`.
+    Cols,
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Join matrices side by side, from left to right.
+    ///
+    /// # Panics
+    /// Panics if `matrices` is empty.
+    /// Panics if the matrices do not have the same number of rows
+    ///
+    /// # Examples
+    /// ```
+    /// let a: Matrix<i32> = Matrix::from_iter(2, 1, vec![1, 3]);
+    /// let b: Matrix<i32> = Matrix::from_iter(2, 1, vec![2, 4]);
+    ///
+    /// assert_eq!(Matrix::hstack(&[&a, &b]), Matrix::from_iter(2, 2, 1..));
+    /// ```
+    pub fn hstack(matrices: &[&Matrix<T>]) -> Matrix<T> {
+        assert!(!matrices.is_empty());
+        let rows = matrices[0].rows;
+        for (i, mat) in matrices.iter().enumerate() {
+            assert_eq!(
+                mat.rows, rows,
+                "matrix {} has {} rows, expected {}",
+                i, mat.rows, rows
+            );
+        }
+
+        let cols = matrices.iter().map(|mat| mat.cols).sum();
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for mat in matrices {
+                data.extend_from_slice(&mat.data[row * mat.cols..(row + 1) * mat.cols]);
+            }
+        }
+        Matrix { rows, cols, data }
+    }
+
+    /// Join matrices on top of each other, from top to bottom.
+    ///
+    /// # Panics
+    /// Panics if `matrices` is empty.
+    /// Panics if the matrices do not have the same number of columns
+    ///
+    /// # Examples
+    /// ```
+    /// let a: Matrix<i32> = Matrix::from_iter(1, 2, vec![1, 2]);
+    /// let b: Matrix<i32> = Matrix::from_iter(1, 2, vec![3, 4]);
+    ///
+    /// assert_eq!(Matrix::vstack(&[&a, &b]), Matrix::from_iter(2, 2, 1..));
+    /// ```
+    pub fn vstack(matrices: &[&Matrix<T>]) -> Matrix<T> {
+        assert!(!matrices.is_empty());
+        let cols = matrices[0].cols;
+        for (i, mat) in matrices.iter().enumerate() {
+            assert_eq!(
+                mat.cols, cols,
+                "matrix {} has {} columns, expected {}",
+                i, mat.cols, cols
+            );
+        }
+
+        let rows = matrices.iter().map(|mat| mat.rows).sum();
+        let mut data = Vec::with_capacity(rows * cols);
+        for mat in matrices {
+            data.extend_from_slice(&mat.data);
+        }
+        Matrix { rows, cols, data }
+    }
+
+    /// Join matrices along an axis, see `Matrix::vstack` and `Matrix::hstack`.
+    ///
+    /// # Panics
+    /// Panics if `matrices` is empty.
+    /// Panics if the matrices do not have the same size along the other axis
+    pub fn concat(matrices: &[&Matrix<T>], axis: Axis) -> Matrix<T> {
+        match axis {
+            Axis::Rows => Matrix::vstack(matrices),
+            Axis::Cols => Matrix::hstack(matrices),
+        }
+    }
+
+    /// Assemble a matrix from a grid of blocks, given row by row.
+    /// The blocks of a row must have the same number of rows,
+    /// and the blocks of a column the same number of columns.
+    ///
+    /// # Panics
+    /// Panics if the grid is empty, or if its rows do not have the same number of blocks.
+    /// Panics if the shapes of the blocks do not match
+    ///
+    /// # Examples
+    /// ```
+    /// let a: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    /// let b: Matrix<i32> = Matrix::from_iter(2, 1, vec![0, 0]);
+    /// let c: Matrix<i32> = Matrix::from_iter(1, 2, vec![0, 0]);
+    /// let d: Matrix<i32> = Matrix::from_iter(1, 1, vec![5]);
+    ///
+    /// assert_eq!(
+    ///     Matrix::block(&[&[&a, &b], &[&c, &d]]),
+    ///     Matrix::from_iter(3, 3, vec![1, 2, 0, 3, 4, 0, 0, 0, 5])
+    /// );
+    /// ```
+    pub fn block(blocks: &[&[&Matrix<T>]]) -> Matrix<T> {
+        assert!(!blocks.is_empty() && !blocks[0].is_empty());
+        let widths: Vec<usize> = blocks[0].iter().map(|mat| mat.cols).collect();
+        let heights: Vec<usize> = blocks.iter().map(|row| row[0].rows).collect();
+
+        for (i, row) in blocks.iter().enumerate() {
+            assert_eq!(
+                row.len(),
+                widths.len(),
+                "block row {} has {} blocks, expected {}",
+                i,
+                row.len(),
+                widths.len()
+            );
+            for (j, mat) in row.iter().enumerate() {
+                assert!(
+                    mat.rows == heights[i] && mat.cols == widths[j],
+                    "block ({}, {}) is {}x{}, expected {}x{}",
+                    i,
+                    j,
+                    mat.rows,
+                    mat.cols,
+                    heights[i],
+                    widths[j]
+                );
+            }
+        }
+
+        let rows = heights.iter().sum();
+        let cols = widths.iter().sum();
+        let mut data = Vec::with_capacity(rows * cols);
+        for (row, &height) in blocks.iter().zip(&heights) {
+            for r in 0..height {
+                for mat in row.iter() {
+                    data.extend_from_slice(&mat.data[r * mat.cols..(r + 1) * mat.cols]);
+                }
+            }
+        }
+        Matrix { rows, cols, data }
+    }
+
+    /// Split the matrix into a grid of blocks, returned row by row.
+    /// This is the inverse of `Matrix::block`.
+    ///
+    /// # Panics
+    /// Panics if a size is `0`.
+    /// Panics if the sizes do not add up to the number of rows and columns of the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 3, 1..);
+    /// let blocks = mat.split_blocks(&[2, 1], &[1, 2]);
+    ///
+    /// assert_eq!(blocks[0][1], Matrix::from_iter(2, 2, vec![2, 3, 5, 6]));
+    /// assert_eq!(blocks[1][0], Matrix::from_iter(1, 1, vec![7]));
+    /// ```
+    pub fn split_blocks(&self, row_sizes: &[usize], col_sizes: &[usize]) -> Vec<Vec<Matrix<T>>> {
+        assert!(row_sizes.iter().chain(col_sizes).all(|&size| size > 0));
+        assert_eq!(row_sizes.iter().sum::<usize>(), self.rows);
+        assert_eq!(col_sizes.iter().sum::<usize>(), self.cols);
+
+        let mut blocks = Vec::with_capacity(row_sizes.len());
+        let mut row = 0;
+        for &rows in row_sizes {
+            let mut block_row = Vec::with_capacity(col_sizes.len());
+            let mut col = 0;
+            for &cols in col_sizes {
+                let mut data = Vec::with_capacity(rows * cols);
+                for r in row..row + rows {
+                    let start = col + r * self.cols;
+                    data.extend_from_slice(&self.data[start..start + cols]);
+                }
+                block_row.push(Matrix { rows, cols, data });
+                col += cols;
+            }
+            blocks.push(block_row);
+            row += rows;
+        }
+        blocks
+    }
+}
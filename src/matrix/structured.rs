@@ -0,0 +1,701 @@
+//! Square matrices with a known structure, stored compactly
+//! and multiplied with `Matrix` without touching their implicit zeros.
+
+use super::iterative::LinearOperator;
+use super::{Matrix, Real, Ring};
+use std::ops::Mul;
+
+/// A diagonal matrix, only storing its main diagonal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagonal<T> {
+    data: Vec<T>,
+}
+
+/// An upper triangular matrix, storing the cells on and above its main diagonal
+/// in packed row-major storage.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpperTriangular<T> {
+    len: usize,
+    /// Row `i` stores the columns `i..len`.
+    data: Vec<T>,
+}
+
+/// A lower triangular matrix, storing the cells on and below its main diagonal
+/// in packed row-major storage.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LowerTriangular<T> {
+    len: usize,
+    /// Row `i` stores the columns `0..=i`.
+    data: Vec<T>,
+}
+
+/// A symmetric matrix, only storing its lower triangle in packed row-major storage.
+/// Converting a `Matrix` keeps its lower triangle, and ignores the upper one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symmetric<T> {
+    len: usize,
+    /// Row `i` stores the columns `0..=i`.
+    data: Vec<T>,
+}
+
+/// A permutation matrix `P`, storing the permuted row indices:
+/// row `i` of `P * A` is row `indices[i]` of `A`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+/// Index of the first cell of a row in packed lower triangular storage.
+fn lower_offset(row: usize) -> usize {
+    row * (row + 1) / 2
+}
+
+/// Index of the first cell of a row in packed upper triangular storage.
+fn upper_offset(len: usize, row: usize) -> usize {
+    row * (2 * len + 1 - row) / 2
+}
+
+impl<T> Diagonal<T> {
+    /// Constructs a diagonal matrix from the values of its main diagonal.
+    ///
+    /// # Panics
+    /// Panics if `diag` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// let diag = Diagonal::new(vec![1, 2, 3]);
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 2, 0..);
+    ///
+    /// assert_eq!(&diag * &mat, Matrix::from_iter(3, 2, vec![0, 1, 4, 6, 12, 15]));
+    /// ```
+    pub fn new(diag: Vec<T>) -> Diagonal<T> {
+        assert!(!diag.is_empty());
+
+        Diagonal { data: diag }
+    }
+
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the amount of columns the matrix has, which is the same as its amount of rows.
+    pub fn cols(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the values of the main diagonal.
+    pub fn diagonal(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T: Ring> Diagonal<T> {
+    /// Convert the matrix to a dense `Matrix`.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let len = self.data.len();
+        let mut data = vec![T::zero(); len * len];
+        for (i, v) in self.data.iter().enumerate() {
+            data[i + i * len] = v.clone();
+        }
+
+        Matrix {
+            rows: len,
+            cols: len,
+            data,
+        }
+    }
+}
+
+impl<T> UpperTriangular<T> {
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the amount of columns the matrix has, which is the same as its amount of rows.
+    pub fn cols(&self) -> usize {
+        self.len
+    }
+
+    /// Try to get a reference to the value at given row & column.
+    /// Returns `None` if `row` or `col` is outside of the matrix or below its diagonal.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row <= col && col < self.len {
+            Some(&self.data[col - row + upper_offset(self.len, row)])
+        } else {
+            None
+        }
+    }
+
+    /// Try to set the cell at given row & column to the given value.
+    /// Returns `false` if `row` or `col` is outside of the matrix or below its diagonal.
+    /// Returns `true` if the cell has been modified.
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> bool {
+        if row <= col && col < self.len {
+            self.data[col - row + upper_offset(self.len, row)] = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Construct the transpose of the matrix.
+    pub fn transpose(&self) -> LowerTriangular<T>
+    where
+        T: Clone,
+    {
+        LowerTriangular {
+            len: self.len,
+            data: (0..self.len)
+                .flat_map(|row| (0..=row).map(move |col| self.get(col, row).unwrap().clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<T> LowerTriangular<T> {
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the amount of columns the matrix has, which is the same as its amount of rows.
+    pub fn cols(&self) -> usize {
+        self.len
+    }
+
+    /// Try to get a reference to the value at given row & column.
+    /// Returns `None` if `row` or `col` is outside of the matrix or above its diagonal.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if col <= row && row < self.len {
+            Some(&self.data[col + lower_offset(row)])
+        } else {
+            None
+        }
+    }
+
+    /// Try to set the cell at given row & column to the given value.
+    /// Returns `false` if `row` or `col` is outside of the matrix or above its diagonal.
+    /// Returns `true` if the cell has been modified.
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> bool {
+        if col <= row && row < self.len {
+            self.data[col + lower_offset(row)] = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Construct the transpose of the matrix.
+    pub fn transpose(&self) -> UpperTriangular<T>
+    where
+        T: Clone,
+    {
+        UpperTriangular {
+            len: self.len,
+            data: (0..self.len)
+                .flat_map(|row| (row..self.len).map(move |col| self.get(col, row).unwrap().clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<T> Symmetric<T> {
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the amount of columns the matrix has, which is the same as its amount of rows.
+    pub fn cols(&self) -> usize {
+        self.len
+    }
+
+    /// Try to get a reference to the value at given row & column.
+    /// Returns `None` if `row` or `col` is outside of the matrix.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        let (row, col) = if col <= row { (row, col) } else { (col, row) };
+        if row < self.len {
+            Some(&self.data[col + lower_offset(row)])
+        } else {
+            None
+        }
+    }
+
+    /// Try to set the cell at given row & column, and its mirror, to the given value.
+    /// Returns `false` if `row` or `col` is outside of the matrix.
+    /// Returns `true` if the cells have been modified.
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> bool {
+        let (row, col) = if col <= row { (row, col) } else { (col, row) };
+        if row < self.len {
+            self.data[col + lower_offset(row)] = value;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+macro_rules! impl_packed {
+    ($($ty:ident: $stored:expr, $cells:expr);*) => {
+        $(
+            impl<T: Ring> $ty<T> {
+                /// Constructs a new *N*x*N* matrix, with all cells set to zero.
+                ///
+                /// # Panics
+                /// Panics if `len` is equal to `0`.
+                pub fn new(len: usize) -> $ty<T> {
+                    assert!(len > 0);
+
+                    $ty {
+                        len,
+                        data: vec![T::zero(); len * (len + 1) / 2],
+                    }
+                }
+
+                /// Convert the matrix to a dense `Matrix`.
+                pub fn to_dense(&self) -> Matrix<T> {
+                    let len = self.len;
+                    Matrix {
+                        rows: len,
+                        cols: len,
+                        data: (0..len * len)
+                            .map(|i| self.get(i / len, i % len).cloned().unwrap_or_else(T::zero))
+                            .collect(),
+                    }
+                }
+            }
+
+            impl<'a, T: Clone> From<&'a Matrix<T>> for $ty<T> {
+                /// Copy the stored cells of a square matrix, ignoring the others.
+                ///
+                /// # Panics
+                /// Panics if the matrix is not square.
+                fn from(mat: &'a Matrix<T>) -> $ty<T> {
+                    assert!(mat.rows == mat.cols);
+
+                    let len = mat.rows;
+                    let stored: fn(usize, usize) -> std::ops::Range<usize> = $stored;
+                    $ty {
+                        len,
+                        data: (0..len)
+                            .flat_map(|row| stored(len, row).map(move |col| (row, col)))
+                            .map(|(row, col)| mat.data[col + row * len].clone())
+                            .collect(),
+                    }
+                }
+            }
+
+            impl<T: Ring> Mul<&Matrix<T>> for &$ty<T> {
+                type Output = Matrix<T>;
+
+                fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+                    assert!(self.len == rhs.rows);
+
+                    let len = self.len;
+                    let cells: fn(usize, usize) -> std::ops::Range<usize> = $cells;
+                    let cols = rhs.cols;
+                    let mut data = vec![T::zero(); len * cols];
+                    for row in 0..len {
+                        for k in cells(len, row) {
+                            let a = self.get(row, k).unwrap();
+                            for (out, b) in data[row * cols..(row + 1) * cols]
+                                .iter_mut()
+                                .zip(&rhs.data[k * cols..(k + 1) * cols])
+                            {
+                                *out = out.clone() + a.clone() * b.clone();
+                            }
+                        }
+                    }
+
+                    Matrix { rows: len, cols, data }
+                }
+            }
+
+            impl<T: Ring> Mul<&$ty<T>> for &Matrix<T> {
+                type Output = Matrix<T>;
+
+                fn mul(self, rhs: &$ty<T>) -> Self::Output {
+                    assert!(self.cols == rhs.len);
+
+                    let len = rhs.len;
+                    let cells: fn(usize, usize) -> std::ops::Range<usize> = $cells;
+                    let mut data = vec![T::zero(); self.rows * len];
+                    for (out, a) in data.chunks_mut(len).zip(self.data.chunks(len)) {
+                        for k in 0..len {
+                            for col in cells(len, k) {
+                                let b = rhs.get(k, col).unwrap();
+                                out[col] = out[col].clone() + a[k].clone() * b.clone();
+                            }
+                        }
+                    }
+
+                    Matrix { rows: self.rows, cols: len, data }
+                }
+            }
+
+            impl<T: Ring> LinearOperator<T> for $ty<T> {
+                fn rows(&self) -> usize {
+                    self.len
+                }
+
+                fn cols(&self) -> usize {
+                    self.len
+                }
+
+                fn mul_vec(&self, x: &[T], y: &mut [T]) {
+                    assert!(x.len() == self.len);
+                    assert!(y.len() == self.len);
+
+                    let cells: fn(usize, usize) -> std::ops::Range<usize> = $cells;
+                    for (row, y) in y.iter_mut().enumerate() {
+                        *y = cells(self.len, row).fold(T::zero(), |acc, col| {
+                            acc + self.get(row, col).unwrap().clone() * x[col].clone()
+                        });
+                    }
+                }
+            }
+        )*
+    };
+}
+
+// Each type lists the columns of a row which are stored, then those which may be non-zero
+impl_packed!(
+    UpperTriangular: |len, row| row..len, |len, row| row..len;
+    LowerTriangular: |_, row| 0..row + 1, |_, row| 0..row + 1;
+    Symmetric: |_, row| 0..row + 1, |len, _| 0..len
+);
+
+impl<T: Ring> UpperTriangular<T> {
+    /// Returns the determinant of the matrix, the product of its diagonal.
+    pub fn determinant(&self) -> T {
+        (0..self.len).fold(T::one(), |acc, i| acc * self.get(i, i).unwrap().clone())
+    }
+}
+
+impl<T: Ring> LowerTriangular<T> {
+    /// Returns the determinant of the matrix, the product of its diagonal.
+    pub fn determinant(&self) -> T {
+        (0..self.len).fold(T::one(), |acc, i| acc * self.get(i, i).unwrap().clone())
+    }
+}
+
+impl<T: Real> UpperTriangular<T> {
+    /// Solve `U * X = B` for `X` by back substitution, in `O(N² * B.cols())` operations.
+    /// Returns `None` if a diagonal cell is zero.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many rows as the matrix.
+    pub fn solve(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        assert!(b.rows == self.len);
+
+        let cols = b.cols;
+        let mut x = b.clone();
+        for row in (0..self.len).rev() {
+            let d = *self.get(row, row).unwrap();
+            if d == T::zero() {
+                return None;
+            }
+            for k in row + 1..self.len {
+                let u = *self.get(row, k).unwrap();
+                for c in 0..cols {
+                    let v = x.data[c + k * cols];
+                    x.data[c + row * cols] -= u * v;
+                }
+            }
+            for c in 0..cols {
+                x.data[c + row * cols] /= d;
+            }
+        }
+
+        Some(x)
+    }
+}
+
+impl<T: Real> LowerTriangular<T> {
+    /// Solve `L * X = B` for `X` by forward substitution, in `O(N² * B.cols())` operations.
+    /// Returns `None` if a diagonal cell is zero.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many rows as the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// let l = LowerTriangular::from(&Matrix::from_iter(2, 2, vec![2.0, 0.0, 1.0, 1.0]));
+    /// let b = Matrix::from_iter(2, 1, vec![4.0, 3.0]);
+    ///
+    /// assert_eq!(l.solve(&b), Some(Matrix::from_iter(2, 1, vec![2.0, 1.0])));
+    /// ```
+    pub fn solve(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        assert!(b.rows == self.len);
+
+        let cols = b.cols;
+        let mut x = b.clone();
+        for row in 0..self.len {
+            let d = *self.get(row, row).unwrap();
+            if d == T::zero() {
+                return None;
+            }
+            for k in 0..row {
+                let l = *self.get(row, k).unwrap();
+                for c in 0..cols {
+                    let v = x.data[c + k * cols];
+                    x.data[c + row * cols] -= l * v;
+                }
+            }
+            for c in 0..cols {
+                x.data[c + row * cols] /= d;
+            }
+        }
+
+        Some(x)
+    }
+}
+
+impl<T: Ring> Mul<&Matrix<T>> for &Diagonal<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        assert!(self.data.len() == rhs.rows);
+
+        Matrix {
+            rows: rhs.rows,
+            cols: rhs.cols,
+            data: rhs
+                .data
+                .chunks(rhs.cols)
+                .zip(&self.data)
+                .flat_map(|(row, d)| row.iter().map(move |v| d.clone() * v.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Ring> Mul<&Diagonal<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Diagonal<T>) -> Self::Output {
+        assert!(self.cols == rhs.data.len());
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .chunks(self.cols)
+                .flat_map(|row| {
+                    row.iter()
+                        .zip(&rhs.data)
+                        .map(|(v, d)| v.clone() * d.clone())
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T: Ring> Mul<&Diagonal<T>> for &Diagonal<T> {
+    type Output = Diagonal<T>;
+
+    fn mul(self, rhs: &Diagonal<T>) -> Self::Output {
+        assert!(self.data.len() == rhs.data.len());
+
+        Diagonal {
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| a.clone() * b.clone())
+                .collect(),
+        }
+    }
+}
+
+impl<T: Ring> LinearOperator<T> for Diagonal<T> {
+    fn rows(&self) -> usize {
+        self.data.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.data.len()
+    }
+
+    fn mul_vec(&self, x: &[T], y: &mut [T]) {
+        assert!(x.len() == self.data.len());
+        assert!(y.len() == self.data.len());
+
+        for ((y, x), d) in y.iter_mut().zip(x).zip(&self.data) {
+            *y = d.clone() * x.clone();
+        }
+    }
+}
+
+impl Permutation {
+    /// Constructs a permutation matrix from its permuted row indices:
+    /// row `i` of `P * A` is row `indices[i]` of `A`.
+    ///
+    /// # Panics
+    /// Panics if `indices` is empty,
+    /// or if it is not a permutation of `0..indices.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// let p = Permutation::new(vec![2, 0, 1]);
+    /// let mat: Matrix<i32> = Matrix::from_iter(3, 1, 0..);
+    ///
+    /// assert_eq!(&p * &mat, Matrix::from_iter(3, 1, vec![2, 0, 1]));
+    /// ```
+    pub fn new(indices: Vec<usize>) -> Permutation {
+        assert!(!indices.is_empty());
+
+        let mut seen = vec![false; indices.len()];
+        for &i in &indices {
+            assert!(i < indices.len() && !seen[i]);
+            seen[i] = true;
+        }
+
+        Permutation { indices }
+    }
+
+    /// Constructs the *N*x*N* identity permutation.
+    ///
+    /// # Panics
+    /// Panics if `len` is equal to `0`.
+    pub fn identity(len: usize) -> Permutation {
+        Permutation::new((0..len).collect())
+    }
+
+    /// Returns the amount of rows the matrix has.
+    pub fn rows(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns the amount of columns the matrix has, which is the same as its amount of rows.
+    pub fn cols(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns the permuted row indices.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Construct the inverse permutation, which is also its transpose.
+    pub fn inverse(&self) -> Permutation {
+        let mut indices = vec![0; self.indices.len()];
+        for (i, &p) in self.indices.iter().enumerate() {
+            indices[p] = i;
+        }
+
+        Permutation { indices }
+    }
+
+    /// Returns the determinant of the matrix: `1` for even permutations, `-1` for odd ones.
+    pub fn sign(&self) -> i32 {
+        // A cycle of length `k` is made of `k - 1` transpositions
+        let mut visited = vec![false; self.indices.len()];
+        let mut cycles = 0;
+        for start in 0..self.indices.len() {
+            if !visited[start] {
+                cycles += 1;
+                let mut i = start;
+                while !visited[i] {
+                    visited[i] = true;
+                    i = self.indices[i];
+                }
+            }
+        }
+
+        if (self.indices.len() - cycles) & 1 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Convert the matrix to a dense `Matrix`.
+    pub fn to_dense<T: Ring>(&self) -> Matrix<T> {
+        let len = self.indices.len();
+        let mut data = vec![T::zero(); len * len];
+        for (row, &col) in self.indices.iter().enumerate() {
+            data[col + row * len] = T::one();
+        }
+
+        Matrix {
+            rows: len,
+            cols: len,
+            data,
+        }
+    }
+}
+
+impl<T: Clone> Mul<&Matrix<T>> for &Permutation {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        assert!(self.indices.len() == rhs.rows);
+
+        let cols = rhs.cols;
+        Matrix {
+            rows: rhs.rows,
+            cols,
+            data: self
+                .indices
+                .iter()
+                .flat_map(|&row| rhs.data[row * cols..(row + 1) * cols].iter().cloned())
+                .collect(),
+        }
+    }
+}
+
+impl<T: Clone> Mul<&Permutation> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Permutation) -> Self::Output {
+        assert!(self.cols == rhs.indices.len());
+
+        // Column `indices[i]` of `A * P` is column `i` of `A`
+        let inverse = rhs.inverse();
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .chunks(self.cols)
+                .flat_map(|row| inverse.indices.iter().map(move |&col| row[col].clone()))
+                .collect(),
+        }
+    }
+}
+
+impl Mul<&Permutation> for &Permutation {
+    type Output = Permutation;
+
+    fn mul(self, rhs: &Permutation) -> Self::Output {
+        assert!(self.indices.len() == rhs.indices.len());
+
+        // Row `i` of `P * Q * A` is row `q[p[i]]` of `A`
+        Permutation {
+            indices: self.indices.iter().map(|&i| rhs.indices[i]).collect(),
+        }
+    }
+}
+
+impl<T: Clone> LinearOperator<T> for Permutation {
+    fn rows(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn mul_vec(&self, x: &[T], y: &mut [T]) {
+        assert!(x.len() == self.indices.len());
+        assert!(y.len() == self.indices.len());
+
+        for (y, &i) in y.iter_mut().zip(&self.indices) {
+            *y = x[i].clone();
+        }
+    }
+}
@@ -0,0 +1,617 @@
+use super::{Matrix, Real};
+
+/// Singular value decomposition `A = U * Σ * Vᵀ` of a *M*x*N* Matrix,
+/// as returned by `Matrix::svd` and `Matrix::svd_full`.
+///
+/// With *K* = min(*M*, *N*), the thin decomposition has a *M*x*K* `u`
+/// and a *K*x*N* `v_t`, while the full decomposition has a *M*x*M* `u`
+/// and a *N*x*N* `v_t`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Svd<T> {
+    /// The left singular vectors, stored as columns.
+    pub u: Matrix<T>,
+    /// The *K* singular values, in decreasing order.
+    pub sigma: Vec<T>,
+    /// The right singular vectors, stored as rows.
+    pub v_t: Matrix<T>,
+}
+
+impl<T: Real> Svd<T> {
+    /// Construct the Σ matrix, with the singular values on its diagonal.
+    /// Its shape is `u.cols()` x `v_t.rows()`, so `u * Σ * v_t`
+    /// rebuilds the decomposed matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(3, 2, (0..).map(|n| n as f64));
+    /// let svd = mat.svd();
+    ///
+    /// let rebuilt = &(&svd.u * &svd.sigma_matrix()) * &svd.v_t;
+    /// ```
+    pub fn sigma_matrix(&self) -> Matrix<T> {
+        let rows = self.u.cols;
+        let cols = self.v_t.rows;
+        let mut sigma = Matrix::from_iter(rows, cols, (0..).map(|_| T::zero()));
+        for (i, &s) in self.sigma.iter().enumerate() {
+            sigma.data[i + i * cols] = s;
+        }
+        sigma
+    }
+}
+
+impl<T: Real> Matrix<T> {
+    /// Compute the thin singular value decomposition of the matrix,
+    /// using a Golub-Kahan bidiagonalization followed by implicit QR steps.
+    ///
+    /// # Panics
+    /// Panics if the matrix contains infinite or NaN cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![3.0, 0.0, 0.0, -4.0]);
+    /// let svd = mat.svd();
+    ///
+    /// assert_eq!(svd.sigma, vec![4.0, 3.0]);
+    /// ```
+    pub fn svd(&self) -> Svd<T> {
+        if self.rows >= self.cols {
+            golub_kahan(self.rows, self.cols, self.data.clone())
+        } else {
+            // A = U Σ Vᵀ  <=>  Aᵀ = V Σ Uᵀ
+            let t = golub_kahan(self.cols, self.rows, self.transpose().data);
+            Svd {
+                u: t.v_t.transpose(),
+                sigma: t.sigma,
+                v_t: t.u.transpose(),
+            }
+        }
+    }
+
+    /// Compute the full singular value decomposition of the matrix.
+    /// Same as `svd`, but `u` and `v_t` are completed to square orthogonal matrices.
+    ///
+    /// # Panics
+    /// Panics if the matrix contains infinite or NaN cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(3, 2, (0..).map(|n| n as f64));
+    /// let svd = mat.svd_full();
+    ///
+    /// assert_eq!(svd.u.rows(), 3);
+    /// assert_eq!(svd.u.cols(), 3);
+    /// ```
+    pub fn svd_full(&self) -> Svd<T> {
+        let mut svd = self.svd();
+        if self.rows > self.cols {
+            svd.u = complete_basis(&svd.u);
+        } else if self.rows < self.cols {
+            svd.v_t = complete_basis(&svd.v_t.transpose()).transpose();
+        }
+        svd
+    }
+
+    /// Compute the Moore-Penrose pseudo-inverse of the matrix.
+    /// Singular values lower or equal to `tol` are considered to be zero.
+    ///
+    /// # Panics
+    /// Panics if the matrix contains infinite or NaN cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![2.0, 0.0, 0.0, 0.0]);
+    /// let pinv = mat.pseudo_inverse(1e-12);
+    ///
+    /// assert_eq!(pinv.get(0, 0).unwrap(), 0.5);
+    /// assert_eq!(pinv.get(1, 1).unwrap(), 0.0);
+    /// ```
+    pub fn pseudo_inverse(&self, tol: T) -> Matrix<T> {
+        let svd = self.svd();
+        let k = svd.sigma.len();
+
+        let mut data = Vec::with_capacity(self.cols * self.rows);
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                let mut acc = T::zero();
+                for (l, &s) in svd.sigma.iter().enumerate() {
+                    if s > tol {
+                        acc += svd.v_t.data[i + l * self.cols] * svd.u.data[l + j * k] / s;
+                    }
+                }
+                data.push(acc);
+            }
+        }
+
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    /// Returns the numerical rank of the matrix,
+    /// i.e. the number of singular values greater than `tol`.
+    ///
+    /// # Panics
+    /// Panics if the matrix contains infinite or NaN cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(3, 3, (0..).map(|n| n as f64));
+    ///
+    /// assert_eq!(mat.rank(1e-9), 2);
+    /// ```
+    pub fn rank(&self, tol: T) -> usize {
+        self.svd().sigma.iter().filter(|&&s| s > tol).count()
+    }
+
+    /// Returns the 2-norm condition number of the matrix,
+    /// i.e. the ratio of its largest and smallest singular values.
+    /// Returns infinity if the matrix is rank-deficient.
+    ///
+    /// # Panics
+    /// Panics if the matrix contains infinite or NaN cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![4.0, 0.0, 0.0, 2.0]);
+    ///
+    /// assert_eq!(mat.condition_number(), 2.0);
+    /// ```
+    pub fn condition_number(&self) -> T {
+        let sigma = self.svd().sigma;
+        let max = sigma[0];
+        let min = sigma[sigma.len() - 1];
+
+        if min == T::zero() {
+            T::infinity()
+        } else {
+            max / min
+        }
+    }
+
+    /// Construct an orthonormal basis of the null space of the matrix,
+    /// stored as the columns of a *N*x*R* Matrix.
+    /// Singular values lower or equal to `max(M, N) * σ_max * ε` are considered to be zero.
+    /// Returns `None` if the null space is trivial.
+    ///
+    /// # Panics
+    /// Panics if the matrix contains infinite or NaN cells.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(1, 2, vec![1.0, 1.0]);
+    /// let null = mat.nullspace().unwrap();
+    ///
+    /// assert_eq!(null.rows(), 2);
+    /// assert_eq!(null.cols(), 1);
+    /// ```
+    pub fn nullspace(&self) -> Option<Matrix<T>> {
+        let svd = self.svd_full();
+        let tol = T::from_f64(self.rows.max(self.cols) as f64) * svd.sigma[0] * T::epsilon();
+        let rank = svd.sigma.iter().filter(|&&s| s > tol).count();
+
+        if rank == self.cols {
+            return None;
+        }
+
+        let nullity = self.cols - rank;
+        let mut data = Vec::with_capacity(self.cols * nullity);
+        for i in 0..self.cols {
+            for j in rank..self.cols {
+                data.push(svd.v_t.data[i + j * self.cols]);
+            }
+        }
+
+        Some(Matrix {
+            rows: self.cols,
+            cols: nullity,
+            data,
+        })
+    }
+}
+
+/// Golub-Kahan-Reinsch SVD of a row-major *M*x*N* matrix with *M* >= *N*.
+/// Adapted from the LINPACK `dsvdc` routine, by way of JAMA.
+fn golub_kahan<T: Real>(m: usize, n: usize, mut a: Vec<T>) -> Svd<T> {
+    debug_assert!(m >= n);
+    assert!(
+        a.iter().all(|v| v.is_finite()),
+        "cannot decompose a matrix with non-finite cells"
+    );
+
+    let zero = T::zero();
+    let one = T::one();
+
+    let mut s = vec![zero; n];
+    let mut e = vec![zero; n];
+    let mut u = vec![zero; m * n];
+    let mut v = vec![zero; n * n];
+    let mut work = vec![zero; m];
+
+    // Reduce A to bidiagonal form, storing the diagonal elements in `s`
+    // and the super-diagonal elements in `e`.
+    let nct = (m - 1).min(n);
+    let nrt = n.saturating_sub(2).min(m);
+    for k in 0..nct.max(nrt) {
+        if k < nct {
+            // Compute the transformation for the k-th column,
+            // and place the k-th diagonal in s[k].
+            s[k] = zero;
+            for i in k..m {
+                s[k] = s[k].hypot(a[i * n + k]);
+            }
+            if s[k] != zero {
+                if a[k * n + k] < zero {
+                    s[k] = -s[k];
+                }
+                for i in k..m {
+                    a[i * n + k] /= s[k];
+                }
+                a[k * n + k] += one;
+            }
+            s[k] = -s[k];
+        }
+
+        for j in k + 1..n {
+            if k < nct && s[k] != zero {
+                // Apply the transformation.
+                let mut t = zero;
+                for i in k..m {
+                    t += a[i * n + k] * a[i * n + j];
+                }
+                t = -t / a[k * n + k];
+                for i in k..m {
+                    let aik = a[i * n + k];
+                    a[i * n + j] += t * aik;
+                }
+            }
+            // Place the k-th row of A into e for the
+            // subsequent calculation of the row transformation.
+            e[j] = a[k * n + j];
+        }
+
+        if k < nct {
+            // Place the transformation in U for subsequent back multiplication.
+            for i in k..m {
+                u[i * n + k] = a[i * n + k];
+            }
+        }
+
+        if k < nrt {
+            // Compute the k-th row transformation,
+            // and place the k-th super-diagonal in e[k].
+            e[k] = zero;
+            for i in k + 1..n {
+                e[k] = e[k].hypot(e[i]);
+            }
+            if e[k] != zero {
+                if e[k + 1] < zero {
+                    e[k] = -e[k];
+                }
+                for i in k + 1..n {
+                    let ek = e[k];
+                    e[i] /= ek;
+                }
+                e[k + 1] += one;
+            }
+            e[k] = -e[k];
+
+            if k + 1 < m && e[k] != zero {
+                // Apply the transformation.
+                for w in work.iter_mut().skip(k + 1) {
+                    *w = zero;
+                }
+                for j in k + 1..n {
+                    for i in k + 1..m {
+                        work[i] += e[j] * a[i * n + j];
+                    }
+                }
+                for j in k + 1..n {
+                    let t = -e[j] / e[k + 1];
+                    for i in k + 1..m {
+                        a[i * n + j] += t * work[i];
+                    }
+                }
+            }
+
+            // Place the transformation in V for subsequent back multiplication.
+            for i in k + 1..n {
+                v[i * n + k] = e[i];
+            }
+        }
+    }
+
+    // Set up the final bidiagonal matrix of order p.
+    let mut p = n;
+    if nct < n {
+        s[nct] = a[nct * n + nct];
+    }
+    if nrt + 1 < p {
+        e[nrt] = a[nrt * n + p - 1];
+    }
+    e[p - 1] = zero;
+
+    // Generate U.
+    for j in nct..n {
+        for i in 0..m {
+            u[i * n + j] = zero;
+        }
+        u[j * n + j] = one;
+    }
+    for k in (0..nct).rev() {
+        if s[k] != zero {
+            for j in k + 1..n {
+                let mut t = zero;
+                for i in k..m {
+                    t += u[i * n + k] * u[i * n + j];
+                }
+                t = -t / u[k * n + k];
+                for i in k..m {
+                    let uik = u[i * n + k];
+                    u[i * n + j] += t * uik;
+                }
+            }
+            for i in k..m {
+                u[i * n + k] = -u[i * n + k];
+            }
+            u[k * n + k] += one;
+            for i in 0..k {
+                u[i * n + k] = zero;
+            }
+        } else {
+            for i in 0..m {
+                u[i * n + k] = zero;
+            }
+            u[k * n + k] = one;
+        }
+    }
+
+    // Generate V.
+    for k in (0..n).rev() {
+        if k < nrt && e[k] != zero {
+            for j in k + 1..n {
+                let mut t = zero;
+                for i in k + 1..n {
+                    t += v[i * n + k] * v[i * n + j];
+                }
+                t = -t / v[(k + 1) * n + k];
+                for i in k + 1..n {
+                    let vik = v[i * n + k];
+                    v[i * n + j] += t * vik;
+                }
+            }
+        }
+        for i in 0..n {
+            v[i * n + k] = zero;
+        }
+        v[k * n + k] = one;
+    }
+
+    // Main iteration loop for the singular values.
+    let pp = p - 1;
+    let eps = T::epsilon();
+    let tiny = T::min_positive() / T::epsilon();
+
+    while p > 0 {
+        // Inspect for negligible elements in the `s` and `e` arrays.
+        // `last` is set to the index of the last negligible super-diagonal
+        // element, or `None` if there is none.
+        let mut last = None;
+        for kk in (0..p - 1).rev() {
+            if e[kk].abs() <= tiny + eps * (s[kk].abs() + s[kk + 1].abs()) {
+                e[kk] = zero;
+                last = Some(kk);
+                break;
+            }
+        }
+
+        let (kase, mut k) = if last.map_or(1, |kk| kk + 2) == p {
+            (Kase::Converged, p - 1)
+        } else {
+            let lower = last.map_or(0, |kk| kk + 1);
+            let mut split = None;
+            for ks in (lower..p).rev() {
+                let t = e[ks].abs() + if ks != lower { e[ks - 1].abs() } else { zero };
+                if s[ks].abs() <= tiny + eps * t {
+                    s[ks] = zero;
+                    split = Some(ks);
+                    break;
+                }
+            }
+
+            match split {
+                None => (Kase::QrStep, lower),
+                Some(ks) if ks == p - 1 => (Kase::DeflateLast, lower),
+                Some(ks) => (Kase::Split, ks + 1),
+            }
+        };
+
+        match kase {
+            // Deflate negligible s[p - 1].
+            Kase::DeflateLast => {
+                let mut f = e[p - 2];
+                e[p - 2] = zero;
+                for j in (k..p - 1).rev() {
+                    let t = s[j].hypot(f);
+                    let cs = s[j] / t;
+                    let sn = f / t;
+                    s[j] = t;
+                    if j != k {
+                        f = -sn * e[j - 1];
+                        e[j - 1] = cs * e[j - 1];
+                    }
+                    rotate(&mut v, n, n, j, p - 1, cs, sn);
+                }
+            }
+
+            // Split at negligible s[k - 1].
+            Kase::Split => {
+                let mut f = e[k - 1];
+                e[k - 1] = zero;
+                for j in k..p {
+                    let t = s[j].hypot(f);
+                    let cs = s[j] / t;
+                    let sn = f / t;
+                    s[j] = t;
+                    f = -sn * e[j];
+                    e[j] = cs * e[j];
+                    rotate(&mut u, m, n, j, k - 1, cs, sn);
+                }
+            }
+
+            // Perform one implicit QR step.
+            Kase::QrStep => {
+                // Calculate the shift.
+                let scale = s[p - 1]
+                    .abs()
+                    .max(s[p - 2].abs())
+                    .max(e[p - 2].abs())
+                    .max(s[k].abs())
+                    .max(e[k].abs());
+                let sp = s[p - 1] / scale;
+                let spm1 = s[p - 2] / scale;
+                let epm1 = e[p - 2] / scale;
+                let sk = s[k] / scale;
+                let ek = e[k] / scale;
+                let b = ((spm1 + sp) * (spm1 - sp) + epm1 * epm1) / T::from_f64(2.0);
+                let c = (sp * epm1) * (sp * epm1);
+                let mut shift = zero;
+                if b != zero || c != zero {
+                    shift = (b * b + c).sqrt();
+                    if b < zero {
+                        shift = -shift;
+                    }
+                    shift = c / (b + shift);
+                }
+                let mut f = (sk + sp) * (sk - sp) + shift;
+                let mut g = sk * ek;
+
+                // Chase zeros.
+                for j in k..p - 1 {
+                    let t = f.hypot(g);
+                    let cs = f / t;
+                    let sn = g / t;
+                    if j != k {
+                        e[j - 1] = t;
+                    }
+                    f = cs * s[j] + sn * e[j];
+                    e[j] = cs * e[j] - sn * s[j];
+                    g = sn * s[j + 1];
+                    s[j + 1] = cs * s[j + 1];
+                    rotate(&mut v, n, n, j, j + 1, cs, sn);
+
+                    let t = f.hypot(g);
+                    let cs = f / t;
+                    let sn = g / t;
+                    s[j] = t;
+                    f = cs * e[j] + sn * s[j + 1];
+                    s[j + 1] = -sn * e[j] + cs * s[j + 1];
+                    g = sn * e[j + 1];
+                    e[j + 1] = cs * e[j + 1];
+                    if j < m - 1 {
+                        rotate(&mut u, m, n, j, j + 1, cs, sn);
+                    }
+                }
+                e[p - 2] = f;
+            }
+
+            // Convergence.
+            Kase::Converged => {
+                // Make the singular value positive.
+                if s[k] <= zero {
+                    s[k] = if s[k] < zero { -s[k] } else { zero };
+                    for i in 0..=pp {
+                        v[i * n + k] = -v[i * n + k];
+                    }
+                }
+
+                // Order the singular values.
+                while k < pp && s[k] < s[k + 1] {
+                    s.swap(k, k + 1);
+                    for i in 0..n {
+                        v.swap(i * n + k, i * n + k + 1);
+                    }
+                    for i in 0..m {
+                        u.swap(i * n + k, i * n + k + 1);
+                    }
+                    k += 1;
+                }
+                p -= 1;
+            }
+        }
+    }
+
+    Svd {
+        u: Matrix {
+            rows: m,
+            cols: n,
+            data: u,
+        },
+        sigma: s,
+        v_t: Matrix {
+            rows: n,
+            cols: n,
+            data: v,
+        }
+        .transpose(),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Kase {
+    DeflateLast,
+    Split,
+    QrStep,
+    Converged,
+}
+
+/// Apply a Givens rotation to the columns `a` and `b` of a row-major matrix.
+fn rotate<T: Real>(x: &mut [T], rows: usize, cols: usize, a: usize, b: usize, cs: T, sn: T) {
+    for i in 0..rows {
+        let xa = x[i * cols + a];
+        let xb = x[i * cols + b];
+        x[i * cols + a] = cs * xa + sn * xb;
+        x[i * cols + b] = -sn * xa + cs * xb;
+    }
+}
+
+/// Complete the orthonormal columns of a *M*x*K* Matrix to a *M*x*M* orthogonal Matrix.
+fn complete_basis<T: Real>(q: &Matrix<T>) -> Matrix<T> {
+    let m = q.rows;
+    let mut basis: Vec<Vec<T>> = (0..q.cols)
+        .map(|j| q.get_col(j).unwrap().cloned().collect())
+        .collect();
+
+    while basis.len() < m {
+        // Pick the canonical vector that is the least aligned with the current basis.
+        let best = (0..m)
+            .map(|i| {
+                let mut x = vec![T::zero(); m];
+                x[i] = T::one();
+                // Orthogonalize twice for numerical stability.
+                for _ in 0..2 {
+                    for b in &basis {
+                        let dot = b
+                            .iter()
+                            .zip(&x)
+                            .fold(T::zero(), |acc, (&b, &x)| acc + b * x);
+                        x.iter_mut().zip(b).for_each(|(x, &b)| *x -= dot * b);
+                    }
+                }
+                let norm = x.iter().fold(T::zero(), |acc, &x| acc.hypot(x));
+                (norm, x)
+            })
+            .fold(None, |best: Option<(T, Vec<T>)>, cur| match best {
+                Some(ref b) if b.0 >= cur.0 => best,
+                _ => Some(cur),
+            })
+            .unwrap();
+
+        let (norm, mut x) = best;
+        x.iter_mut().for_each(|x| *x /= norm);
+        basis.push(x);
+    }
+
+    Matrix::from_iter(m, m, (0..m).flat_map(|i| basis.iter().map(move |b| b[i])))
+}
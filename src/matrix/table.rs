@@ -0,0 +1,290 @@
+use super::Matrix;
+use std::fmt::{self, Display, Write};
+
+/// LaTeX environment written by `Matrix::to_latex`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LatexEnvironment {
+    /// `pmatrix`, a matrix between parentheses (requires `amsmath`).
+    PMatrix,
+    /// `bmatrix`, a matrix between brackets (requires `amsmath`).
+    BMatrix,
+    /// `tabular`, a table which can have row and column labels.
+    Tabular,
+}
+
+/// Closure formatting a cell of a table.
+pub type CellFormatter<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+/// Options of `Matrix::to_latex`, `Matrix::to_markdown_table` and `Matrix::to_html_table`.
+pub struct TableOptions<'a, T> {
+    /// Labels written before each row.
+    pub row_labels: Option<Vec<String>>,
+    /// Labels written above each column.
+    pub col_labels: Option<Vec<String>>,
+    /// Closure formatting the cells, e.g. `|v| format!("{:.2}", v)`.
+    /// Its output is written verbatim, so that it can contain markup;
+    /// without it, cells are formatted with `Display` and escaped.
+    pub format: Option<CellFormatter<'a, T>>,
+}
+
+impl<'a, T> Default for TableOptions<'a, T> {
+    /// No labels, and cells formatted with `Display`.
+    fn default() -> Self {
+        TableOptions {
+            row_labels: None,
+            col_labels: None,
+            format: None,
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for TableOptions<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TableOptions")
+            .field("row_labels", &self.row_labels)
+            .field("col_labels", &self.col_labels)
+            .field("format", &self.format.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    /// Format the matrix as a LaTeX environment.
+    /// Labels are only written by `LatexEnvironment::Tabular`, but are checked for every environment.
+    ///
+    /// # Panics
+    /// Panics if labels are given without one label per row or column.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    ///
+    /// assert_eq!(
+    ///     mat.to_latex(LatexEnvironment::BMatrix, &TableOptions::default()),
+    ///     "\\begin{bmatrix}\n1 & 2 \\\\\n3 & 4\n\\end{bmatrix}\n"
+    /// );
+    /// ```
+    pub fn to_latex(&self, env: LatexEnvironment, options: &TableOptions<T>) -> String {
+        let cells = self.format_cells(options, escape_latex);
+        let (row_labels, col_labels) = self.labels(options, escape_latex);
+        let mut out = String::new();
+
+        match env {
+            LatexEnvironment::PMatrix | LatexEnvironment::BMatrix => {
+                let name = if env == LatexEnvironment::PMatrix {
+                    "pmatrix"
+                } else {
+                    "bmatrix"
+                };
+                let rows: Vec<String> = cells.iter().map(|row| row.join(" & ")).collect();
+                writeln!(out, "\\begin{{{}}}", name).unwrap();
+                writeln!(out, "{}", rows.join(" \\\\\n")).unwrap();
+                writeln!(out, "\\end{{{}}}", name).unwrap();
+            }
+            LatexEnvironment::Tabular => {
+                let spec = "r".repeat(self.cols);
+                if row_labels.is_some() {
+                    writeln!(out, "\\begin{{tabular}}{{l|{}}}", spec).unwrap();
+                } else {
+                    writeln!(out, "\\begin{{tabular}}{{{}}}", spec).unwrap();
+                }
+
+                if let Some(col_labels) = col_labels {
+                    let mut header = col_labels;
+                    if row_labels.is_some() {
+                        header.insert(0, String::new());
+                    }
+                    writeln!(out, "{} \\\\", header.join(" & ")).unwrap();
+                    out.push_str("\\hline\n");
+                }
+                for (i, mut row) in cells.into_iter().enumerate() {
+                    if let Some(row_labels) = &row_labels {
+                        row.insert(0, row_labels[i].clone());
+                    }
+                    writeln!(out, "{} \\\\", row.join(" & ")).unwrap();
+                }
+                out.push_str("\\end{tabular}\n");
+            }
+        }
+        out
+    }
+
+    /// Format the matrix as a Markdown table, with right-aligned cells.
+    /// Without column labels, the header of the table is left empty.
+    ///
+    /// # Panics
+    /// Panics if labels are given without one label per row or column.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    /// let options = TableOptions {
+    ///     row_labels: Some(vec!["x".to_string(), "y".to_string()]),
+    ///     col_labels: Some(vec!["a".to_string(), "b".to_string()]),
+    ///     ..TableOptions::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     mat.to_markdown_table(&options),
+    ///     "|   | a | b |\n|:--|--:|--:|\n| x | 1 | 2 |\n| y | 3 | 4 |\n"
+    /// );
+    /// ```
+    pub fn to_markdown_table(&self, options: &TableOptions<T>) -> String {
+        let cells = self.format_cells(options, escape_markdown);
+        let (row_labels, col_labels) = self.labels(options, escape_markdown);
+
+        let mut header = col_labels.unwrap_or_else(|| vec![String::new(); self.cols]);
+        let mut rule = vec!["--:"; self.cols];
+        if row_labels.is_some() {
+            header.insert(0, String::new());
+            rule.insert(0, ":--");
+        }
+
+        let mut out = String::new();
+        write_markdown_row(&mut out, &header);
+        writeln!(out, "|{}|", rule.join("|")).unwrap();
+        for (i, mut row) in cells.into_iter().enumerate() {
+            if let Some(row_labels) = &row_labels {
+                row.insert(0, row_labels[i].clone());
+            }
+            write_markdown_row(&mut out, &row);
+        }
+        out
+    }
+
+    /// Format the matrix as an HTML table.
+    /// Labels are written in `th` cells, in a `thead` for the column labels.
+    ///
+    /// # Panics
+    /// Panics if labels are given without one label per row or column.
+    ///
+    /// # Examples
+    /// ```
+    /// let mat: Matrix<f64> = Matrix::from_iter(1, 2, vec![0.5, 2.0]);
+    /// let options = TableOptions {
+    ///     format: Some(Box::new(|v: &f64| format!("{:.1}", v))),
+    ///     ..TableOptions::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     mat.to_html_table(&options),
+    ///     "<table>\n  <tbody>\n    <tr><td>0.5</td><td>2.0</td></tr>\n  </tbody>\n</table>\n"
+    /// );
+    /// ```
+    pub fn to_html_table(&self, options: &TableOptions<T>) -> String {
+        let cells = self.format_cells(options, escape_html);
+        let (row_labels, col_labels) = self.labels(options, escape_html);
+
+        let mut out = String::from("<table>\n");
+        if let Some(col_labels) = col_labels {
+            out.push_str("  <thead>\n    <tr>");
+            if row_labels.is_some() {
+                out.push_str("<th></th>");
+            }
+            for label in col_labels {
+                write!(out, "<th>{}</th>", label).unwrap();
+            }
+            out.push_str("</tr>\n  </thead>\n");
+        }
+
+        out.push_str("  <tbody>\n");
+        for (i, row) in cells.into_iter().enumerate() {
+            out.push_str("    <tr>");
+            if let Some(row_labels) = &row_labels {
+                write!(out, "<th>{}</th>", row_labels[i]).unwrap();
+            }
+            for cell in row {
+                write!(out, "<td>{}</td>", cell).unwrap();
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("  </tbody>\n</table>\n");
+        out
+    }
+
+    /// Format the cells row by row, with the closure of the options
+    /// or with `Display` and `escape`.
+    fn format_cells(
+        &self,
+        options: &TableOptions<T>,
+        escape: fn(&str) -> String,
+    ) -> Vec<Vec<String>> {
+        self.data
+            .chunks(self.cols)
+            .map(|row| {
+                row.iter()
+                    .map(|v| match &options.format {
+                        Some(format) => format(v),
+                        None => escape(&v.to_string()),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Escaped row and column labels of the options.
+    fn labels(
+        &self,
+        options: &TableOptions<T>,
+        escape: fn(&str) -> String,
+    ) -> (Option<Vec<String>>, Option<Vec<String>>) {
+        if let Some(labels) = &options.row_labels {
+            assert!(labels.len() == self.rows);
+        }
+        if let Some(labels) = &options.col_labels {
+            assert!(labels.len() == self.cols);
+        }
+
+        let escape_all = |labels: &Vec<String>| labels.iter().map(|l| escape(l)).collect();
+        (
+            options.row_labels.as_ref().map(escape_all),
+            options.col_labels.as_ref().map(escape_all),
+        )
+    }
+}
+
+fn write_markdown_row(out: &mut String, cells: &[String]) {
+    for cell in cells {
+        if cell.is_empty() {
+            out.push_str("|   ");
+        } else {
+            write!(out, "| {} ", cell).unwrap();
+        }
+    }
+    out.push_str("|\n");
+}
+
+fn escape_latex(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            '\\' => out.push_str("\\textbackslash{}"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_markdown(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('|', "\\|")
+}
+
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
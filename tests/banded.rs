@@ -0,0 +1,120 @@
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use simple_matrix::iterative::LinearOperator;
+use simple_matrix::{BandedMatrix, Matrix};
+
+/// Band matrix with small integer values, so that its determinant is exact.
+#[derive(Debug, Clone)]
+struct ABanded(BandedMatrix<f64>);
+
+/// Diagonally dominant tridiagonal matrix.
+#[derive(Debug, Clone)]
+struct ATridiagonal(BandedMatrix<f64>);
+
+impl Arbitrary for ABanded {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let s = std::cmp::max(2, g.size() / 10);
+
+        let len = g.gen_range(1, s);
+        let lower = g.gen_range(0, 4);
+        let upper = g.gen_range(0, 4);
+        let mut mat = BandedMatrix::new(len, lower, upper);
+        for row in 0..len {
+            for col in 0..len {
+                mat.set(row, col, f64::from(g.gen_range(-9, 10)));
+            }
+        }
+
+        ABanded(mat)
+    }
+}
+
+impl Arbitrary for ATridiagonal {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let s = std::cmp::max(3, g.size());
+
+        let len = g.gen_range(2, s);
+        let mut off = |len| -> Vec<f64> { (0..len).map(|_| g.gen_range(-1.0, 1.0)).collect() };
+        let sub = off(len - 1);
+        let sup = off(len - 1);
+        let diag: Vec<f64> = (0..len).map(|_| g.gen_range(2.5, 10.0)).collect();
+
+        ATridiagonal(BandedMatrix::tridiagonal(&sub, &diag, &sup))
+    }
+}
+
+fn to_i64(mat: &Matrix<f64>) -> Matrix<i64> {
+    Matrix::from_iter(mat.rows(), mat.cols(), mat.iter().map(|&v| v as i64))
+}
+
+fn residual(a: &impl LinearOperator<f64>, x: &[f64], b: &[f64]) -> f64 {
+    let mut ax = vec![0.0; b.len()];
+    a.mul_vec(x, &mut ax);
+    ax.iter()
+        .zip(b)
+        .map(|(ax, b)| (ax - b).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn storage() {
+    let dense = Matrix::from_iter(4, 4, vec![1, 2, 0, 0, 0, 3, 4, 0, 5, 0, 6, 7, 0, 0, 8, 9]);
+    let mut banded = BandedMatrix::from(&dense);
+
+    assert_eq!((banded.lower(), banded.upper()), (2, 1));
+    assert_eq!(banded.rows(), 4);
+    assert_eq!(banded.to_dense(), dense);
+    assert_eq!(banded.get(2, 0), Some(&5));
+    assert_eq!(banded.get(1, 0), Some(&0));
+    assert_eq!(banded.get(0, 2), None);
+    assert_eq!(banded.get(4, 3), None);
+    assert!(!banded.set(3, 0, 1));
+    assert!(banded.set(3, 1, 1));
+
+    let mut y = vec![0; 4];
+    banded.mul_vec(&[1, 1, 1, 1], &mut y);
+    assert_eq!(y, vec![3, 7, 18, 18]);
+}
+
+#[test]
+fn singular() {
+    let mat = BandedMatrix::tridiagonal(&[1.0, 1.0], &[1.0, 1.0, 1.0], &[1.0, 0.0]);
+
+    assert!(mat.lu().is_none());
+    assert!(mat.solve_tridiagonal(&[1.0; 3]).is_none());
+
+    // Needs pivoting, but is not singular
+    let mat = BandedMatrix::tridiagonal(&[1.0], &[0.0, 1.0], &[1.0]);
+    let x = mat.lu().unwrap().solve(&[2.0, 3.0]);
+    assert_eq!(x, vec![1.0, 2.0]);
+    assert!(mat.solve_tridiagonal(&[2.0, 3.0]).is_none());
+}
+
+quickcheck! {
+    fn qcheck_lu(t: ABanded) -> bool {
+        let mat = &t.0;
+        let det = to_i64(&mat.to_dense()).determinant().unwrap();
+
+        match mat.lu() {
+            None => det == 0,
+            Some(lu) => {
+                let b: Vec<f64> = (0..mat.rows()).map(|i| i as f64 - 2.0).collect();
+                let x = lu.solve(&b);
+                let scale = 1.0 + x.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+
+                (lu.determinant() - det as f64).abs() <= 1e-9 * (det as f64).abs().max(1.0)
+                && residual(mat, &x, &b) <= 1e-9 * scale
+            }
+        }
+    }
+
+    fn qcheck_thomas(t: ATridiagonal) -> bool {
+        let mat = &t.0;
+        let b: Vec<f64> = (0..mat.rows()).map(|i| (i as f64).cos()).collect();
+        let x = mat.solve_tridiagonal(&b).unwrap();
+        let y = mat.lu().unwrap().solve(&b);
+
+        residual(mat, &x, &b) <= 1e-12
+        && x.iter().zip(&y).all(|(x, y)| (x - y).abs() <= 1e-12)
+        && BandedMatrix::from(&mat.to_dense()).to_dense() == mat.to_dense()
+    }
+}
@@ -0,0 +1,119 @@
+use simple_matrix::{BinaryError, Matrix};
+
+fn save<T: simple_matrix::BinaryElement>(mat: &Matrix<T>) -> Vec<u8> {
+    let mut out = Vec::new();
+    mat.save(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn layout() {
+    let mat: Matrix<u16> = Matrix::from_iter(1, 2, vec![1, 0x0203]);
+    let out = save(&mat);
+
+    assert_eq!(out.len(), 32 + 4 + 4);
+    assert_eq!(&out[..8], b"SMATRIX\0");
+    assert_eq!(&out[8..13], &[1, 0, b'u', 2, 0]);
+    assert_eq!(&out[16..24], &1u64.to_le_bytes());
+    assert_eq!(&out[24..32], &2u64.to_le_bytes());
+    assert_eq!(&out[32..36], &[1, 0, 3, 2]);
+    // zlib.crc32 of the first 36 bytes
+    assert_eq!(&out[36..], &0xeb1a_a430u32.to_le_bytes());
+}
+
+#[test]
+fn round_trip() {
+    let floats: Matrix<f64> = Matrix::from_iter(37, 53, (0..).map(|i| f64::from(i) * -0.37));
+    assert_eq!(Matrix::<f64>::load(&save(&floats)[..]).unwrap(), floats);
+
+    let ints: Matrix<i8> = Matrix::from_iter(3, 1, vec![-128, 0, 127]);
+    assert_eq!(Matrix::<i8>::load(&save(&ints)[..]).unwrap(), ints);
+
+    let bools = Matrix::from_iter(1, 3, vec![true, false, true]);
+    assert_eq!(Matrix::<bool>::load(&save(&bools)[..]).unwrap(), bools);
+}
+
+#[test]
+fn big_endian() {
+    let mut file = save(&Matrix::<u16>::from_iter(1, 2, vec![1, 2]));
+    file[12] = 1;
+    file[32..36].copy_from_slice(&[0, 1, 0, 2]);
+    let len = file.len();
+    file.truncate(len - 4);
+    let crc = checksum(&file);
+    file.extend_from_slice(&crc.to_le_bytes());
+
+    assert_eq!(
+        Matrix::<u16>::load(&file[..]).unwrap(),
+        Matrix::from_iter(1, 2, vec![1, 2])
+    );
+}
+
+#[test]
+fn errors() {
+    let file = save(&Matrix::<f64>::from_iter(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+
+    match Matrix::<f32>::load(&file[..]) {
+        Err(BinaryError::TypeMismatch { expected, found }) => {
+            assert_eq!(expected, "f4");
+            assert_eq!(found, "f8");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let mut corrupted = file.clone();
+    corrupted[40] ^= 0x10;
+    assert!(matches!(
+        Matrix::<f64>::load(&corrupted[..]),
+        Err(BinaryError::ChecksumMismatch { .. })
+    ));
+
+    let mut magic = file.clone();
+    magic[0] = b'X';
+    assert!(matches!(
+        Matrix::<f64>::load(&magic[..]),
+        Err(BinaryError::InvalidMagic)
+    ));
+
+    let mut version = file.clone();
+    version[8] = 2;
+    assert!(matches!(
+        Matrix::<f64>::load(&version[..]),
+        Err(BinaryError::UnsupportedVersion(2))
+    ));
+
+    let mut huge = file.clone();
+    huge[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(matches!(
+        Matrix::<f64>::load(&huge[..]),
+        Err(BinaryError::InvalidHeader(_))
+    ));
+
+    let mut long = file.clone();
+    long[16..24].copy_from_slice(&(1u64 << 40).to_le_bytes());
+    assert!(matches!(
+        Matrix::<f64>::load(&long[..]),
+        Err(BinaryError::Io(_))
+    ));
+
+    assert!(matches!(
+        Matrix::<f64>::load(&file[..file.len() - 1]),
+        Err(BinaryError::Io(_))
+    ));
+}
+
+/// Bitwise CRC-32, independent from the table-driven implementation of the crate.
+fn checksum(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &b in bytes {
+        crc ^= u32::from(b);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,109 @@
+use simple_matrix::{CastError, Matrix};
+use std::convert::TryFrom;
+
+#[derive(Debug, PartialEq)]
+struct Meters(f64);
+
+impl From<i32> for Meters {
+    fn from(v: i32) -> Self {
+        Meters(f64::from(v))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Even(u32);
+
+impl TryFrom<u32> for Even {
+    type Error = String;
+
+    fn try_from(v: u32) -> Result<Self, Self::Error> {
+        if v & 1 == 0 {
+            Ok(Even(v))
+        } else {
+            Err(format!("{} is odd", v))
+        }
+    }
+}
+
+#[test]
+fn map() {
+    let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+
+    let squares = mat.map_ref(|v| v * v);
+    assert_eq!(squares, Matrix::from_iter(2, 3, vec![1, 4, 9, 16, 25, 36]));
+
+    let strings = mat.map(|v| v.to_string());
+    assert_eq!((strings.rows(), strings.cols()), (2, 3));
+    assert_eq!(strings.get(1, 2).unwrap(), "6");
+}
+
+#[test]
+fn cast() {
+    let mat: Matrix<i32> = Matrix::from_iter(1, 2, vec![1, -2]);
+
+    let floats: Matrix<f64> = mat.clone().cast();
+    assert_eq!(floats, Matrix::from_iter(1, 2, vec![1.0, -2.0]));
+
+    let meters: Matrix<Meters> = mat.cast();
+    assert_eq!(
+        meters,
+        Matrix::from_iter(1, 2, vec![Meters(1.0), Meters(-2.0)])
+    );
+}
+
+#[test]
+fn try_cast() {
+    let mat: Matrix<i64> = Matrix::from_iter(2, 2, vec![0, 255, 256, -1]);
+
+    let err = mat.clone().try_cast::<u8>().unwrap_err();
+    assert_eq!((err.row, err.col), (1, 0));
+    assert_eq!(
+        err.to_string(),
+        format!("cannot convert cell (1, 0): {}", err.error)
+    );
+
+    let ints: Matrix<i16> = mat.try_cast().unwrap();
+    assert_eq!(ints, Matrix::from_iter(2, 2, vec![0, 255, 256, -1]));
+
+    let evens: Matrix<u32> = Matrix::from_iter(1, 3, vec![2, 4, 7]);
+    assert_eq!(
+        evens.clone().try_cast::<Even>(),
+        Err(CastError {
+            row: 0,
+            col: 2,
+            error: "7 is odd".to_string(),
+        })
+    );
+    assert_eq!(
+        evens.map(|v| v * 2).try_cast::<Even>().unwrap(),
+        Matrix::from_iter(1, 3, vec![Even(4), Even(8), Even(14)])
+    );
+}
+
+#[test]
+fn float_casts() {
+    let mat: Matrix<f64> = Matrix::from_iter(
+        2,
+        3,
+        vec![-0.5, 1.5, -2.5, 1e10, f64::NEG_INFINITY, f64::NAN],
+    );
+
+    assert_eq!(
+        mat.saturating_cast::<i16>(),
+        Matrix::from_iter(2, 3, vec![0, 1, -2, i16::MAX, i16::MIN, 0])
+    );
+    assert_eq!(
+        mat.round_cast::<i16>(),
+        Matrix::from_iter(2, 3, vec![-1, 2, -3, i16::MAX, i16::MIN, 0])
+    );
+    assert_eq!(
+        mat.round_cast::<u32>(),
+        Matrix::from_iter(2, 3, vec![0, 2, 0, u32::MAX, 0, 0])
+    );
+
+    let small: Matrix<f32> = Matrix::from_iter(1, 2, vec![254.6, 255.5]);
+    assert_eq!(
+        small.round_cast::<u8>(),
+        Matrix::from_iter(1, 2, vec![255, 255])
+    );
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "csv")]
+
+use simple_matrix::{CsvError, CsvReadOptions, CsvWriteOptions, Matrix, MissingValues};
+
+fn read<T>(data: &str, options: &CsvReadOptions<T>) -> Result<Matrix<T>, CsvError>
+where
+    T: std::str::FromStr + Clone,
+    T::Err: std::fmt::Display,
+{
+    Matrix::read_csv(data.as_bytes(), options)
+}
+
+#[test]
+fn read_options() {
+    let data = "# exported\r\nx;\"y;z\"\r\n 1.5 ; -2\r\n\r\n\"3\";NA\r\n";
+    let options = CsvReadOptions {
+        delimiter: ';',
+        header_rows: 2,
+        missing_tokens: vec!["NA".to_string()],
+        missing: MissingValues::Fill(0.0),
+        ..CsvReadOptions::default()
+    };
+    assert_eq!(
+        read(data, &options).unwrap(),
+        Matrix::from_iter(2, 2, vec![1.5, -2.0, 3.0, 0.0])
+    );
+
+    let data = "1,2,3\n4,5,6";
+    let mat: Matrix<u8> = read(data, &CsvReadOptions::default()).unwrap();
+    assert_eq!(mat, Matrix::from_iter(2, 3, 1..));
+}
+
+#[test]
+fn read_errors() {
+    let options: CsvReadOptions<i32> = CsvReadOptions::default();
+
+    match read("1,2\n3,x4\n", &options) {
+        Err(CsvError::InvalidValue {
+            line: 2,
+            column: 2,
+            cell,
+            ..
+        }) => assert_eq!(cell, "x4"),
+        other => panic!("{:?}", other),
+    }
+
+    // Lines are counted inside quoted cells, which are not trimmed
+    let strings: CsvReadOptions<String> = CsvReadOptions::default();
+    match read("\"1\n\",2\n3,\n", &strings) {
+        Err(err @ CsvError::MissingValue { line: 3, column: 2 }) => {
+            assert_eq!(err.to_string(), "line 3, column 2: missing value")
+        }
+        other => panic!("{:?}", other),
+    }
+
+    match read("1,2\n\n3\n", &options) {
+        Err(CsvError::RaggedRow {
+            line: 3,
+            expected: 2,
+            found: 1,
+        }) => {}
+        other => panic!("{:?}", other),
+    }
+
+    match read("1,\"2\n", &options) {
+        Err(CsvError::UnterminatedQuote { line: 1, column: 2 }) => {}
+        other => panic!("{:?}", other),
+    }
+
+    match read("\"1\"2,3\n", &options) {
+        Err(CsvError::UnexpectedCharacter {
+            line: 1,
+            column: 1,
+            found: '2',
+        }) => {}
+        other => panic!("{:?}", other),
+    }
+
+    match read(
+        "a,b\n",
+        &CsvReadOptions {
+            header_rows: 1,
+            ..options
+        },
+    ) {
+        Err(CsvError::Empty) => {}
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn write_read() {
+    let mat = Matrix::from_iter(
+        2,
+        2,
+        vec![
+            "plain",
+            "with,comma",
+            " spaced ",
+            "with \"quotes\"\nand lines",
+        ]
+        .into_iter()
+        .map(String::from),
+    );
+
+    let mut out = Vec::new();
+    mat.write_csv(&mut out, &CsvWriteOptions::default())
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(out.clone()).unwrap(),
+        "plain,\"with,comma\"\n\" spaced \",\"with \"\"quotes\"\"\nand lines\"\n"
+    );
+
+    // Quoted cells keep their whitespace, even when trimming
+    let options = CsvReadOptions::default();
+    assert_eq!(Matrix::read_csv(&out[..], &options).unwrap(), mat);
+
+    // Empty cells of single column matrices are quoted so that their row is not skipped
+    let column: Matrix<String> =
+        Matrix::from_iter(3, 1, vec!["a", "", "b"].into_iter().map(String::from));
+    let mut out = Vec::new();
+    column
+        .write_csv(&mut out, &CsvWriteOptions::default())
+        .unwrap();
+    assert_eq!(String::from_utf8(out.clone()).unwrap(), "a\n\"\"\nb\n");
+
+    let options = CsvReadOptions {
+        missing: MissingValues::Fill(String::new()),
+        ..CsvReadOptions::default()
+    };
+    assert_eq!(Matrix::read_csv(&out[..], &options).unwrap(), column);
+    match Matrix::<String>::read_csv(&out[..], &CsvReadOptions::default()) {
+        Err(CsvError::MissingValue { line: 2, column: 1 }) => {}
+        other => panic!("{:?}", other),
+    }
+
+    // Other empty cells are left unquoted, and are missing as well
+    let row: Matrix<String> = Matrix::from_iter(1, 2, vec!["a", ""].into_iter().map(String::from));
+    let mut out = Vec::new();
+    row.write_csv(&mut out, &CsvWriteOptions::default())
+        .unwrap();
+    assert_eq!(out, b"a,\n");
+    assert_eq!(Matrix::read_csv(&out[..], &options).unwrap(), row);
+    match Matrix::<String>::read_csv(&out[..], &CsvReadOptions::default()) {
+        Err(CsvError::MissingValue { line: 1, column: 2 }) => {}
+        other => panic!("{:?}", other),
+    }
+
+    let floats: Matrix<f64> = Matrix::from_iter(3, 2, (0..).map(|n| f64::from(n) / 7.0));
+    let mut out = Vec::new();
+    floats
+        .write_csv(
+            &mut out,
+            &CsvWriteOptions {
+                delimiter: '\t',
+                header: Some(vec!["a".to_string(), "b".to_string()]),
+                quote_all: true,
+                ..CsvWriteOptions::default()
+            },
+        )
+        .unwrap();
+    assert!(out.starts_with(b"\"a\"\t\"b\"\n\"0\"\t"));
+
+    let options = CsvReadOptions {
+        delimiter: '\t',
+        header_rows: 1,
+        ..CsvReadOptions::default()
+    };
+    assert_eq!(Matrix::read_csv(&out[..], &options).unwrap(), floats);
+}
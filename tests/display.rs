@@ -0,0 +1,93 @@
+use simple_matrix::{DisplayOptions, DisplayStyle, Matrix};
+
+#[test]
+fn brackets() {
+    let mat: Matrix<i32> = Matrix::from_iter(2, 3, vec![1, -20, 3, 400, 5, 6]);
+
+    assert_eq!(mat.to_string(), "[   1  -20  3 ]\n[ 400    5  6 ]");
+    assert_eq!(
+        format!("{:<4}", mat),
+        "[ 1     -20   3    ]\n[ 400   5     6    ]"
+    );
+    assert_eq!(
+        format!("{:*^5}", mat),
+        "[ **1**  *-20*  **3** ]\n[ *400*  **5**  **6** ]"
+    );
+}
+
+#[test]
+fn precision() {
+    let mat = Matrix::from_iter(2, 2, vec![1.0, -2.5, 30.0, 1.0 / 3.0]);
+
+    assert_eq!(
+        format!("{:.3}", mat),
+        "[  1.000  -2.500 ]\n[ 30.000   0.333 ]"
+    );
+    assert_eq!(
+        format!("{:8.1}", mat),
+        "[      1.0      -2.5 ]\n[     30.0       0.3 ]"
+    );
+
+    let options = DisplayOptions {
+        precision: Some(2),
+        ..DisplayOptions::default()
+    };
+    // The precision of the options overrides the one of the format specifier
+    assert_eq!(
+        format!("{:.4}", mat.format_with(options)),
+        "[  1.00  -2.50 ]\n[ 30.00   0.33 ]"
+    );
+}
+
+#[test]
+fn styles() {
+    let mat: Matrix<u8> = Matrix::from_iter(2, 2, vec![1, 20, 3, 4]);
+
+    let boxed = mat.format_with(DisplayOptions {
+        style: DisplayStyle::Box,
+        ..DisplayOptions::default()
+    });
+    assert_eq!(
+        boxed.to_string(),
+        "┌       ┐\n│ 1  20 │\n│ 3   4 │\n└       ┘"
+    );
+
+    let plain = mat.format_with(DisplayOptions {
+        style: DisplayStyle::Plain,
+        ..DisplayOptions::default()
+    });
+    assert_eq!(plain.to_string(), "1  20\n3   4");
+}
+
+#[test]
+fn elision() {
+    let mat: Matrix<usize> = Matrix::from_iter(30, 25, 0..);
+    let text = mat.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[0], "[   0    1    2  …   22   23   24 ]");
+    assert_eq!(lines[3], "[   ⋮    ⋮    ⋮  ⋱    ⋮    ⋮    ⋮ ]");
+    assert_eq!(lines[6], "[ 725  726  727  …  747  748  749 ]");
+
+    let options = DisplayOptions {
+        style: DisplayStyle::Plain,
+        max_rows: 30,
+        max_cols: 3,
+        edge_items: 1,
+        ..DisplayOptions::default()
+    };
+    let text = mat.format_with(options).to_string();
+    assert_eq!(text.lines().count(), 30);
+    assert_eq!(text.lines().next().unwrap(), "  0  …   24");
+
+    let small: Matrix<usize> = Matrix::from_iter(5, 5, 0..);
+    let options = DisplayOptions {
+        max_rows: 4,
+        max_cols: 4,
+        edge_items: 3,
+        ..DisplayOptions::default()
+    };
+    // Nothing to elide if the edges cover the whole matrix
+    assert_eq!(small.format_with(options).to_string().lines().count(), 5);
+}
@@ -0,0 +1,276 @@
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use simple_matrix::{Field, Matrix, Ring};
+use std::ops::{Add, Div, Mul, Sub};
+
+const RANGE: i64 = 20;
+
+#[derive(Debug, Clone)]
+struct ASquare(Matrix<i64>);
+
+impl Arbitrary for ASquare {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let length = g.gen_range(1, 6);
+
+        // Low-rank matrices are generated more often than random ones
+        let rank = g.gen_range(0, length + 1);
+        let mut m = Matrix::from_iter(length, length, (0..).map(|_| g.gen_range(-RANGE, RANGE)));
+        for row in rank..length {
+            for col in 0..length {
+                let v = m[(0, col)] * (row as i64);
+                m[(row, col)] = if rank == 0 { 0 } else { v };
+            }
+        }
+
+        ASquare(m)
+    }
+}
+
+/// Exact rational number, always kept in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rational(i64, i64);
+
+impl Rational {
+    fn new(num: i64, den: i64) -> Rational {
+        fn gcd(a: i64, b: i64) -> i64 {
+            if b == 0 {
+                a.abs()
+            } else {
+                gcd(b, a % b)
+            }
+        }
+
+        let g = gcd(num, den) * den.signum();
+        Rational(num / g, den / g)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Rational {
+        Rational(n, 1)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+    fn add(self, rhs: Rational) -> Rational {
+        Rational::new(self.0 * rhs.1 + rhs.0 * self.1, self.1 * rhs.1)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, rhs: Rational) -> Rational {
+        Rational::new(self.0 * rhs.1 - rhs.0 * self.1, self.1 * rhs.1)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, rhs: Rational) -> Rational {
+        Rational::new(self.0 * rhs.0, self.1 * rhs.1)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    fn div(self, rhs: Rational) -> Rational {
+        Rational::new(self.0 * rhs.1, self.1 * rhs.0)
+    }
+}
+
+impl Ring for Rational {
+    fn zero() -> Rational {
+        Rational(0, 1)
+    }
+    fn one() -> Rational {
+        Rational(1, 1)
+    }
+}
+
+impl Field for Rational {}
+
+/// Determinant by cofactor expansion along the first row.
+fn laplace(m: &Matrix<i64>) -> i64 {
+    if m.rows() == 1 {
+        return m[(0, 0)];
+    }
+
+    (0..m.cols())
+        .map(|skip| {
+            let minor = Matrix::from_iter(
+                m.rows() - 1,
+                m.cols() - 1,
+                (1..m.rows()).flat_map(|r| {
+                    (0..m.cols())
+                        .filter(move |&c| c != skip)
+                        .map(move |c| m[(r, c)])
+                }),
+            );
+            let sign = if skip % 2 == 0 { 1 } else { -1 };
+            sign * m[(0, skip)] * laplace(&minor)
+        })
+        .sum()
+}
+
+#[test]
+fn determinant() {
+    let m: Matrix<i64> = Matrix::from_iter(3, 3, vec![0, 2, 1, 3, 0, 4, 5, 6, 0]);
+    assert_eq!(m.determinant(), Some(58));
+
+    let singular: Matrix<i32> = Matrix::from_iter(3, 3, 0..);
+    assert_eq!(singular.determinant(), Some(0));
+    assert_eq!(singular.rank(), Some(2));
+
+    let wide: Matrix<i128> = Matrix::from_iter(2, 4, vec![1, 2, 3, 4, 2, 4, 6, 9]);
+    assert_eq!(wide.rank(), Some(2));
+    assert_eq!(Matrix::<i8>::new(3, 2).rank(), Some(0));
+}
+
+#[test]
+fn determinant_overflow() {
+    let m: Matrix<i8> = Matrix::from_iter(2, 2, vec![100, 1, 1, 100]);
+    assert_eq!(m.determinant(), None);
+
+    let m: Matrix<i64> = Matrix::from_iter(2, 2, vec![i64::MAX, 0, 0, 2]);
+    assert_eq!(m.determinant(), None);
+    assert_eq!(m.rank(), None);
+}
+
+#[test]
+fn rref() {
+    let m = Matrix::from_iter(
+        3,
+        4,
+        vec![1, 2, 1, -1, 3, 8, 1, 4, 0, 4, 1, 0]
+            .into_iter()
+            .map(Rational::from),
+    );
+    let expected = Matrix::from_iter(
+        3,
+        4,
+        vec![
+            Rational(1, 1),
+            Rational(0, 1),
+            Rational(0, 1),
+            Rational(2, 5),
+            Rational(0, 1),
+            Rational(1, 1),
+            Rational(0, 1),
+            Rational(7, 10),
+            Rational(0, 1),
+            Rational(0, 1),
+            Rational(1, 1),
+            Rational(-14, 5),
+        ],
+    );
+
+    assert_eq!(m.rref(), expected);
+}
+
+#[test]
+fn pow() {
+    let fib: Matrix<u64> = Matrix::from_iter(2, 2, vec![1, 1, 1, 0]);
+
+    assert_eq!(fib.pow(0), Matrix::from_iter(2, 2, vec![1, 0, 0, 1]));
+    assert_eq!(fib.pow(1), fib);
+    assert_eq!(fib.pow(90)[(0, 1)], 2_880_067_194_370_816_120);
+
+    assert_eq!(fib.pow_mod(1000, 1_000_000_007)[(0, 1)], 517_691_607);
+    assert_eq!(fib.pow_mod(0, 1), Matrix::new(2, 2));
+
+    let neg: Matrix<i8> = Matrix::from_iter(1, 1, vec![-3]);
+    assert_eq!(neg.pow_mod(3, 100)[(0, 0)], 73);
+    assert_eq!(neg.pow_mod(5, i8::MAX)[(0, 0)], 11);
+
+    // Products of 128-bit cells do not fit in any integer type
+    let fib128: Matrix<u128> = Matrix::from_iter(2, 2, vec![1, 1, 1, 0]);
+    assert_eq!(
+        fib128.pow_mod(1000, (1 << 127) - 1),
+        Matrix::from_iter(
+            2,
+            2,
+            vec![
+                29_224_528_388_336_326_472_260_836_914_735_646_364,
+                164_910_399_610_961_358_473_789_066_595_681_074_099,
+                164_910_399_610_961_358_473_789_066_595_681_074_099,
+                34_455_312_237_844_199_730_159_074_034_938_677_992,
+            ]
+        )
+    );
+    let big: Matrix<u128> = Matrix::from_iter(2, 2, vec![3, 1 << 127, 5, 7]);
+    assert_eq!(
+        big.pow_mod(12345, u128::MAX - 158),
+        Matrix::from_iter(
+            2,
+            2,
+            vec![
+                66_166_711_860_296_285_608_461_823_069_995_086_326,
+                255_319_398_550_038_126_757_941_415_682_598_511_788,
+                337_078_924_676_988_369_730_098_146_362_208_910_770,
+                335_829_851_601_886_981_392_540_340_159_762_214_942,
+            ]
+        )
+    );
+    let signed: Matrix<i128> = Matrix::from_iter(2, 2, vec![-3, 1, -(1 << 126), 0]);
+    assert_eq!(
+        signed.pow_mod(77, i128::MAX),
+        Matrix::from_iter(
+            2,
+            2,
+            vec![
+                137_927_548_679_203_459_518_170_232_054_366_424_025,
+                72_681_731_014_318_583_531_571_440_955_400_216_602,
+                133_800_317_953_309_939_965_901_583_238_183_997_426,
+                15_690_374_801_220_746_649_509_947_488_798_862_377,
+            ]
+        )
+    );
+
+    let rot: Matrix<f64> = Matrix::from_iter(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+    assert_eq!(
+        rot.pow(4),
+        Matrix::from_iter(2, 2, vec![1.0, 0.0, 0.0, 1.0])
+    );
+}
+
+quickcheck! {
+    fn qcheck_pow(t: ASquare, a: u8, b: u8) -> bool {
+        let m = &t.0;
+        let (a, b) = (u64::from(a % 4), u64::from(b % 4));
+        let modulus = 1_000_000_007;
+
+        let mut naive = m.pow(0);
+        for _ in 0..a {
+            naive = &naive * m;
+        }
+
+        (m.pow(a) == naive)
+        && (&m.pow(a) * &m.pow(b) == m.pow(a + b))
+        && (m.pow_mod(a, modulus) == Matrix::from_iter(m.rows(), m.cols(), naive.iter().map(|v| v.rem_euclid(modulus))))
+        && (&m.pow_mod(a << 40, modulus) * &m.pow_mod(b << 40, modulus))
+            .iter()
+            .map(|v| v % modulus)
+            .eq(m.pow_mod((a + b) << 40, modulus).iter().cloned())
+    }
+
+    fn qcheck_determinant(t: ASquare) -> bool {
+        let m = &t.0;
+        let det = m.determinant().unwrap();
+
+        (det == laplace(m))
+        && (m.transpose().determinant() == Some(det))
+        && ((det == 0) == (m.rank() != Some(m.rows())))
+    }
+
+    fn qcheck_rank(t: ASquare) -> bool {
+        let m = &t.0;
+        let rref = Matrix::from_iter(m.rows(), m.cols(), m.iter().map(|&v| Rational::from(v))).rref();
+        let pivots = (0..rref.rows())
+            .filter(|&r| rref.get_row(r).unwrap().any(|&v| v != Rational::zero()))
+            .count();
+
+        (m.rank() == Some(pivots))
+        && (m.transpose().rank() == Some(pivots))
+        && (rref.rref() == rref)
+    }
+}
@@ -0,0 +1,233 @@
+use simple_matrix::iterative::{
+    bicgstab, cg, gmres, Jacobi, LinearOperator, Preconditioner, Settings, Solution, Ssor,
+};
+use simple_matrix::Matrix;
+
+/// 1-D Laplacian `tridiag(-1, 2, -1)`, symmetric positive definite.
+fn laplacian(n: usize) -> Matrix<f64> {
+    let mut m = Matrix::new(n, n);
+    for i in 0..n {
+        m[(i, i)] = 2.0;
+        if i > 0 {
+            m[(i, i - 1)] = -1.0;
+            m[(i - 1, i)] = -1.0;
+        }
+    }
+    m
+}
+
+/// Non-symmetric convection-diffusion operator.
+fn convection(n: usize) -> Matrix<f64> {
+    let mut m = laplacian(n);
+    for i in 1..n {
+        m[(i, i - 1)] -= 0.5;
+        m[(i - 1, i)] += 0.5;
+    }
+    m
+}
+
+/// Matrix-free diagonal operator.
+struct Diagonal(Vec<f64>);
+
+impl LinearOperator<f64> for Diagonal {
+    fn rows(&self) -> usize {
+        self.0.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.0.len()
+    }
+
+    fn mul_vec(&self, x: &[f64], y: &mut [f64]) {
+        for ((y, x), d) in y.iter_mut().zip(x).zip(&self.0) {
+            *y = x * d;
+        }
+    }
+}
+
+fn residual<A: LinearOperator<f64>>(a: &A, b: &[f64], solution: &Solution<f64>) -> f64 {
+    let mut ax = vec![0.0; b.len()];
+    a.mul_vec(&solution.x, &mut ax);
+    let r: f64 = ax.iter().zip(b).map(|(ax, b)| (b - ax) * (b - ax)).sum();
+    let b: f64 = b.iter().map(|b| b * b).sum();
+    (r / b).sqrt()
+}
+
+fn check(a: &impl LinearOperator<f64>, b: &[f64], solution: &Solution<f64>, tol: f64) {
+    assert!(solution.converged, "{:?}", solution.history);
+    assert_eq!(solution.history.len(), solution.iterations + 1);
+    assert!(*solution.history.last().unwrap() <= tol);
+    let res = residual(a, b, solution);
+    assert!(res <= 10.0 * tol, "{} {:?}", res, solution.history);
+}
+
+#[test]
+fn conjugate_gradient() {
+    let a = laplacian(50);
+    let b: Vec<f64> = (0..50).map(|i| (i as f64).sin()).collect();
+    let settings = Settings {
+        tol: 1e-10,
+        ..Settings::default()
+    };
+
+    let plain = cg(&a, &b, &settings);
+    check(&a, &b, &plain, 1e-10);
+    // Exact arithmetic converges in at most n iterations
+    assert!(plain.iterations <= 60);
+
+    let ssor = Ssor::new(&a, 1.5);
+    let preconditioned = cg(
+        &a,
+        &b,
+        &Settings {
+            preconditioner: Some(&ssor),
+            ..settings
+        },
+    );
+    check(&a, &b, &preconditioned, 1e-10);
+    assert!(preconditioned.iterations < plain.iterations);
+
+    let warm = cg(
+        &a,
+        &b,
+        &Settings {
+            x0: Some(&plain.x),
+            ..settings
+        },
+    );
+    assert_eq!(warm.iterations, 0);
+}
+
+#[test]
+fn bicgstab_gmres() {
+    let a = convection(40);
+    let b: Vec<f64> = (0..40).map(|i| 1.0 + (i % 3) as f64).collect();
+    let jacobi = Jacobi::new(&a);
+
+    for preconditioner in [None, Some(&jacobi as _)].iter() {
+        let settings = Settings {
+            tol: 1e-10,
+            preconditioner: *preconditioner,
+            ..Settings::default()
+        };
+
+        check(&a, &b, &bicgstab(&a, &b, &settings), 1e-10);
+
+        let full = gmres(
+            &a,
+            &b,
+            &Settings {
+                restart: 40,
+                ..settings
+            },
+        );
+        check(&a, &b, &full, 1e-10);
+        // Without restarts, the residual never increases
+        assert!(full
+            .history
+            .windows(2)
+            .all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+
+        let restarted = gmres(
+            &a,
+            &b,
+            &Settings {
+                restart: 5,
+                ..settings
+            },
+        );
+        check(&a, &b, &restarted, 1e-10);
+    }
+}
+
+#[test]
+fn matrix_free() {
+    let a = Diagonal((1..=20).map(f64::from).collect());
+    let b = vec![1.0; 20];
+    let settings = Settings::default();
+
+    let solution = gmres(&a, &b, &settings);
+    check(&a, &b, &solution, 1e-8);
+    assert!(solution.iterations <= 20);
+
+    check(&a, &b, &cg(&a, &b, &settings), 1e-8);
+    check(&a, &b, &bicgstab(&a, &b, &settings), 1e-8);
+}
+
+#[test]
+fn gmres_lucky_breakdown() {
+    // The Krylov spaces of these diagonal matrices contain the exact solution
+    // after as many iterations as `b` has distinct eigencomponents
+    let cases = [
+        (Diagonal(vec![2.0; 4]), vec![3.0, 0.0, 0.0, 0.0], 1),
+        (
+            Diagonal(vec![1.0, 1.0, 4.0, 4.0]),
+            vec![1.0, 1.0, 1.0, 1.0],
+            2,
+        ),
+    ];
+    let settings = Settings {
+        tol: 1e-14,
+        ..Settings::default()
+    };
+
+    for (a, b, iterations) in cases.iter() {
+        let solution = gmres(a, b, &settings);
+        check(a, b, &solution, 1e-14);
+        assert_eq!(solution.iterations, *iterations);
+        assert!((solution.history.last().unwrap() - residual(a, b, &solution)).abs() <= 1e-15);
+    }
+}
+
+/// Non-linear preconditioner damping large residuals,
+/// which breaks the residual estimate of GMRES.
+struct Damping;
+
+impl Preconditioner<f64> for Damping {
+    fn precondition(&self, r: &[f64], z: &mut [f64]) {
+        let scale = 1.0 + 0.1 * r.iter().map(|r| r * r).sum::<f64>().sqrt();
+        for (z, r) in z.iter_mut().zip(r) {
+            *z = r / scale;
+        }
+    }
+}
+
+#[test]
+fn gmres_true_residual() {
+    let a = convection(20);
+    let b = vec![1.0; 20];
+    let settings = Settings {
+        tol: 1e-10,
+        preconditioner: Some(&Damping),
+        ..Settings::default()
+    };
+
+    // The estimate reaches the tolerance long before the true residual,
+    // which GMRES restarts from until it converges
+    let solution = gmres(&a, &b, &settings);
+    check(&a, &b, &solution, 1e-10);
+    assert!((solution.history.last().unwrap() - residual(&a, &b, &solution)).abs() <= 1e-15);
+}
+
+#[test]
+fn max_iterations() {
+    let a = laplacian(100);
+    let b = vec![1.0; 100];
+    let settings = Settings {
+        tol: 1e-12,
+        max_iter: 5,
+        ..Settings::default()
+    };
+
+    for solution in [
+        cg(&a, &b, &settings),
+        bicgstab(&a, &b, &settings),
+        gmres(&a, &b, &settings),
+    ]
+    .iter()
+    {
+        assert!(!solution.converged);
+        assert_eq!(solution.iterations, 5);
+        assert_eq!(solution.history.len(), 6);
+    }
+}
@@ -0,0 +1,260 @@
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use simple_matrix::{assert_matrix_approx_eq, Matrix, MatrixFunctionError, Norm};
+
+const TOL: f64 = 1e-9;
+
+#[derive(Debug, Clone)]
+struct AMatrix<T>(Matrix<T>);
+
+impl Arbitrary for AMatrix<f64> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let s = std::cmp::max(2, g.size() / 4); // rows & cols != 0
+
+        let cols = g.gen_range(1, s);
+        let rows = g.gen_range(1, s);
+
+        AMatrix(Matrix::from_iter(
+            rows,
+            cols,
+            (0..).map(|_| g.gen_range(-100.0, 100.0)),
+        ))
+    }
+}
+
+fn close(a: &Matrix<f64>, b: &Matrix<f64>, tol: f64) -> bool {
+    a.approx_eq(b, tol, 0.0)
+}
+
+fn identity(length: usize) -> Matrix<f64> {
+    let mut m = Matrix::new(length, length);
+    for i in 0..length {
+        m.set(i, i, 1.0);
+    }
+    m
+}
+
+fn scale(a: &Matrix<f64>) -> f64 {
+    a.iter().fold(1.0, |acc: f64, x| acc.max(x.abs()))
+}
+
+#[test]
+fn svd_diagonal() {
+    let a = Matrix::from_iter(3, 3, vec![2.0, 0.0, 0.0, 0.0, -5.0, 0.0, 0.0, 0.0, 3.0]);
+    let svd = a.svd();
+
+    assert_eq!(svd.sigma, vec![5.0, 3.0, 2.0]);
+    assert!(close(
+        &(&(&svd.u * &svd.sigma_matrix()) * &svd.v_t),
+        &a,
+        TOL
+    ));
+}
+
+#[test]
+fn svd_rank_deficient() {
+    let a: Matrix<f64> = Matrix::from_iter(3, 3, (1..).map(f64::from));
+
+    assert_eq!(a.rank(1e-9), 2);
+    assert!(a.condition_number() > 1e12);
+
+    let null = a.nullspace().unwrap();
+    assert_eq!((null.rows(), null.cols()), (3, 1));
+    assert!(close(&(&a * &null), &Matrix::new(3, 1), 1e-9));
+    assert!(identity(3).nullspace().is_none());
+}
+
+#[test]
+fn svd_f32() {
+    let a: Matrix<f32> = Matrix::from_iter(2, 3, vec![3.0, 2.0, 2.0, 2.0, 3.0, -2.0]);
+    let svd = a.svd();
+
+    assert!((svd.sigma[0] - 5.0).abs() < 1e-5);
+    assert!((svd.sigma[1] - 3.0).abs() < 1e-5);
+}
+
+#[test]
+fn norms() {
+    let a = Matrix::from_iter(2, 3, vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0]);
+
+    assert_eq!(a.norm(Norm::One), 9.0);
+    assert_eq!(a.norm(Norm::Inf), 15.0);
+    assert_eq!(a.norm(Norm::Max), 6.0);
+    assert!((a.norm(Norm::Frobenius) - 91f64.sqrt()).abs() < TOL);
+    assert!((a.norm(Norm::Spectral) - a.svd().sigma[0]).abs() < TOL);
+
+    assert_eq!(a.entrywise_norm(1.0), 21.0);
+    assert!((a.entrywise_norm(2.0) - a.norm(Norm::Frobenius)).abs() < TOL);
+    assert_eq!(a.entrywise_norm(f64::INFINITY), 6.0);
+
+    assert_eq!(a.distance(&a, Norm::Frobenius), 0.0);
+    assert_eq!(a.distance(&Matrix::new(2, 3), Norm::Max), 6.0);
+}
+
+#[test]
+fn approx_eq() {
+    let a = Matrix::from_iter(1, 3, vec![1.0, 1e6, f64::INFINITY]);
+    let b = Matrix::from_iter(1, 3, vec![1.0 + 1e-12, 1e6 + 1e-4, f64::INFINITY]);
+
+    assert!(a.approx_eq(&b, 1e-9, 1e-9));
+    assert!(!a.approx_eq(&b, 1e-9, 0.0));
+    assert_eq!(a.approx_mismatches(&b, 1e-9, 0.0), vec![(0, 1)]);
+    assert!(!a.approx_eq(&a.transpose(), 1.0, 1.0));
+
+    let nan = Matrix::from_iter(1, 1, vec![f64::NAN]);
+    assert!(!nan.approx_eq(&nan, 1.0, 1.0));
+    assert!(!nan.ulps_eq(&nan, u32::MAX));
+
+    let c = Matrix::from_iter(1, 2, vec![-0.0, 1.0]);
+    let d = Matrix::from_iter(1, 2, vec![0.0, 1.0 + f64::EPSILON]);
+    assert!(c.ulps_eq(&d, 1));
+    assert!(!c.ulps_eq(&d, 0));
+
+    assert_matrix_approx_eq!(a, b, 1e-9, 1e-9);
+    assert_matrix_approx_eq!(c, d, ulps = 1);
+    assert_matrix_approx_eq!(
+        Matrix::from_iter(1, 1, vec![0.1 + 0.2]),
+        Matrix::from_iter(1, 1, vec![0.3])
+    );
+}
+
+#[test]
+#[should_panic(expected = "1 of 4 cells differ:\n    (1, 0): left = 3.0, right = 3.5")]
+fn assert_approx_eq_report() {
+    let a = Matrix::from_iter(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    let b = Matrix::from_iter(2, 2, vec![1.0, 2.0, 3.5, 4.0]);
+
+    assert_matrix_approx_eq!(a, b, 1e-9, 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "shapes differ: left is 1x2, right is 2x1")]
+fn assert_approx_eq_shapes() {
+    let a: Matrix<f64> = Matrix::new(1, 2);
+
+    assert_matrix_approx_eq!(a, a.transpose());
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_traits() {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_ne};
+
+    let a = Matrix::from_iter(1, 2, vec![1.0, 1e6]);
+    let b = Matrix::from_iter(1, 2, vec![1.0 + 1e-12, 1e6 + 1e-4]);
+
+    assert_abs_diff_eq!(a, b, epsilon = 1e-3);
+    assert_relative_eq!(a, b, max_relative = 1e-9);
+    assert_ulps_eq!(a, a.clone());
+    assert!(relative_ne!(a, a.transpose()));
+}
+
+#[test]
+fn expm() {
+    // Rotation generators, with and without scaling and squaring
+    for &t in &[1e-3f64, 0.5, 2.0, 30.0] {
+        let a = Matrix::from_iter(2, 2, vec![0.0, -t, t, 0.0]);
+        let rot = Matrix::from_iter(2, 2, vec![t.cos(), -t.sin(), t.sin(), t.cos()]);
+        assert_matrix_approx_eq!(a.expm(), rot, 1e-13, 1e-13);
+    }
+
+    // Nilpotent matrix: exp(N) = I + N + N²/2
+    let n = Matrix::from_iter(3, 3, vec![0.0, 1.0, 2.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0]);
+    let expected = Matrix::from_iter(3, 3, vec![1.0, 1.0, 3.5, 0.0, 1.0, 3.0, 0.0, 0.0, 1.0]);
+    assert_matrix_approx_eq!(n.expm(), expected, 1e-14, 1e-14);
+
+    let d = Matrix::from_iter(2, 2, vec![-20.0, 0.0, 0.0, 10.0]);
+    let expected = Matrix::from_iter(2, 2, vec![(-20f64).exp(), 0.0, 0.0, 10f64.exp()]);
+    assert_matrix_approx_eq!(d.expm(), expected, 0.0, 1e-13);
+}
+
+#[test]
+#[should_panic(expected = "finite norm")]
+fn expm_nan() {
+    let a: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, f64::NAN, 0.0, 1.0]);
+    a.expm();
+}
+
+#[test]
+#[should_panic(expected = "finite norm")]
+fn expm_overflow() {
+    let a: Matrix<f64> = Matrix::from_iter(2, 2, vec![f64::MAX, 0.0, f64::MAX, 0.0]);
+    a.expm();
+}
+
+#[test]
+fn sqrtm_logm() {
+    let a = Matrix::from_iter(3, 3, vec![4.0, 1.0, 0.0, 1.0, 5.0, 2.0, 0.0, 2.0, 6.0]);
+    let sqrt = a.sqrtm().unwrap();
+    assert_matrix_approx_eq!(&sqrt * &sqrt, a, 1e-12, 1e-12);
+
+    let log = a.logm().unwrap();
+    assert_matrix_approx_eq!(log.expm(), a, 1e-11, 1e-11);
+
+    let rot = Matrix::from_iter(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+    let log = rot.logm().unwrap();
+    let pi_2 = std::f64::consts::FRAC_PI_2;
+    assert_matrix_approx_eq!(
+        log,
+        Matrix::from_iter(2, 2, vec![0.0, -pi_2, pi_2, 0.0]),
+        1e-11,
+        0.0
+    );
+    assert_matrix_approx_eq!(rot.sqrtm().unwrap().pow(2), rot, 1e-12, 0.0);
+
+    let neg = Matrix::from_iter(2, 2, vec![-4.0, 0.0, 0.0, 1.0]);
+    assert_eq!(neg.sqrtm(), Err(MatrixFunctionError::NoRealSquareRoot));
+    assert_eq!(neg.logm(), Err(MatrixFunctionError::NoRealLogarithm));
+
+    let singular: Matrix<f64> = Matrix::new(2, 2);
+    assert_eq!(singular.sqrtm(), Err(MatrixFunctionError::NoRealSquareRoot));
+    assert_eq!(singular.logm(), Err(MatrixFunctionError::NoRealLogarithm));
+}
+
+quickcheck! {
+    fn qcheck_svd(t: AMatrix<f64>) -> bool {
+        let a = &t.0;
+        let k = std::cmp::min(a.rows(), a.cols());
+        let svd = a.svd();
+        let full = a.svd_full();
+
+        (svd.u.rows(), svd.u.cols(), svd.v_t.rows(), svd.v_t.cols()) == (a.rows(), k, k, a.cols())
+        && (full.u.rows(), full.u.cols(), full.v_t.rows(), full.v_t.cols()) == (a.rows(), a.rows(), a.cols(), a.cols())
+        && svd.sigma.windows(2).all(|w| w[0] >= w[1] && w[1] >= 0.0)
+        && close(&(&svd.u.transpose() * &svd.u), &identity(k), TOL)
+        && close(&(&svd.v_t * &svd.v_t.transpose()), &identity(k), TOL)
+        && close(&(&full.u.transpose() * &full.u), &identity(a.rows()), TOL)
+        && close(&(&full.v_t * &full.v_t.transpose()), &identity(a.cols()), TOL)
+        && close(&(&(&svd.u * &svd.sigma_matrix()) * &svd.v_t), a, TOL * scale(a))
+        && close(&(&(&full.u * &full.sigma_matrix()) * &full.v_t), a, TOL * scale(a))
+    }
+
+    fn qcheck_pseudo_inverse(t: AMatrix<f64>) -> bool {
+        let a = &t.0;
+        let pinv = a.pseudo_inverse(1e-9);
+
+        close(&(&(a * &pinv) * a), a, TOL * scale(a))
+        && close(&(&(&pinv * a) * &pinv), &pinv, TOL * scale(&pinv))
+    }
+
+    fn qcheck_expm_logm(t: AMatrix<f64>) -> bool {
+        let a = &t.0;
+        let k = std::cmp::min(a.rows(), a.cols());
+        let mut small = Matrix::from_iter(k, k, a.iter().cloned());
+        small.apply_mut(|v| *v /= 100.0 * k as f64);
+
+        let exp = small.expm();
+        let neg = {
+            let mut neg = small.clone();
+            neg.apply_mut(|v| *v = -*v);
+            neg.expm()
+        };
+
+        (&exp * &neg).approx_eq(&identity(k), 1e-12, 0.0)
+        && exp.logm().unwrap().approx_eq(&small, 1e-12, 0.0)
+        && exp.sqrtm().unwrap().approx_eq(&{
+            let mut half = small.clone();
+            half.apply_mut(|v| *v /= 2.0);
+            half.expm()
+        }, 1e-12, 0.0)
+    }
+}
@@ -0,0 +1,181 @@
+use simple_matrix::{
+    CooMatrix, CscMatrix, CsrMatrix, Matrix, MatrixMarketError, MatrixMarketField,
+    MatrixMarketSymmetry,
+};
+
+fn read_dense(data: &str) -> Result<Matrix<f64>, MatrixMarketError> {
+    Matrix::read_matrix_market(data.as_bytes())
+}
+
+fn write_dense(mat: &Matrix<f64>, symmetry: MatrixMarketSymmetry) -> String {
+    let mut out = Vec::new();
+    mat.write_matrix_market(&mut out, MatrixMarketField::Real, symmetry)
+        .unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn read_formats() {
+    let coordinate = "%%MatrixMarket matrix coordinate real general\n\
+                      % A comment\n\
+                      \n\
+                      3 4 4\n\
+                      1 1 1.5\n\
+                      3 2 -2\n\
+                      1 4 3e2\n\
+                      1 1 0.5\n";
+    let expected = Matrix::from_iter(
+        3,
+        4,
+        vec![
+            2.0, 0.0, 0.0, 300.0, 0.0, 0.0, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0,
+        ],
+    );
+    assert_eq!(read_dense(coordinate).unwrap(), expected);
+
+    let coo: CooMatrix<f64> = CooMatrix::read_matrix_market(coordinate.as_bytes()).unwrap();
+    assert_eq!(coo.nnz(), 4);
+    assert_eq!(coo.to_dense(), expected);
+
+    let array = "%%MatrixMarket matrix array integer symmetric\n3 3\n1\n2\n3\n4\n5\n6\n";
+    let mat: Matrix<i32> = Matrix::read_matrix_market(array.as_bytes()).unwrap();
+    assert_eq!(
+        mat,
+        Matrix::from_iter(3, 3, vec![1, 2, 3, 2, 4, 5, 3, 5, 6])
+    );
+
+    let skew = "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n0\n3\n";
+    let coo: CooMatrix<f64> = CooMatrix::read_matrix_market(skew.as_bytes()).unwrap();
+    assert_eq!(coo.nnz(), 4);
+    assert_eq!(
+        coo.to_dense(),
+        Matrix::from_iter(3, 3, vec![0.0, -1.0, 0.0, 1.0, 0.0, -3.0, 0.0, 3.0, 0.0])
+    );
+
+    let pattern = "%%MatrixMarket MATRIX Coordinate Pattern Symmetric\n2 2 2\n2 1\n2 2\n";
+    let mat: Matrix<u8> = Matrix::read_matrix_market(pattern.as_bytes()).unwrap();
+    assert_eq!(mat, Matrix::from_iter(2, 2, vec![0, 1, 1, 1]));
+}
+
+#[test]
+fn read_errors() {
+    let error = |data: &str| match read_dense(data) {
+        Err(MatrixMarketError::Syntax { line, .. }) => line,
+        other => panic!("{:?}", other),
+    };
+
+    assert_eq!(
+        error("%MatrixMarket matrix array real general\n1 1\n1\n"),
+        1
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix array real general\n%\n1\n1\n"),
+        3
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix array real general\n2 1\n1\nx\n"),
+        4
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix array real general\n2 1\n1\n"),
+        3
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix array real general\n1 1\n1\n2\n"),
+        4
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n"),
+        3
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1\n"),
+        3
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1\n"),
+        3
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix coordinate real symmetric\n2 3 0\n"),
+        2
+    );
+
+    match read_dense("%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1 0\n") {
+        Err(err @ MatrixMarketError::Unsupported { .. }) => {
+            assert_eq!(
+                err.to_string(),
+                "unsupported Matrix Market qualifier \"complex\""
+            )
+        }
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn write_read() {
+    let mat: Matrix<f64> = Matrix::from_iter(3, 2, (0..).map(|n| f64::from(n) / 4.0));
+    let out = write_dense(&mat, MatrixMarketSymmetry::General);
+    assert_eq!(
+        out,
+        "%%MatrixMarket matrix array real general\n3 2\n0\n0.5\n1\n0.25\n0.75\n1.25\n"
+    );
+    assert_eq!(read_dense(&out).unwrap(), mat);
+
+    let sym = Matrix::from_iter(3, 3, vec![1.0, 2.0, 3.0, 2.0, 4.0, 5.0, 3.0, 5.0, 6.0]);
+    let out = write_dense(&sym, MatrixMarketSymmetry::Symmetric);
+    assert_eq!(out.lines().count(), 8);
+    assert_eq!(read_dense(&out).unwrap(), sym);
+
+    let skew = Matrix::from_iter(3, 3, vec![0.0, -1.0, 2.0, 1.0, 0.0, -3.0, -2.0, 3.0, 0.0]);
+    let out = write_dense(&skew, MatrixMarketSymmetry::SkewSymmetric);
+    assert_eq!(out.lines().count(), 5);
+    assert_eq!(read_dense(&out).unwrap(), skew);
+}
+
+#[test]
+fn write_read_sparse() {
+    let mat: Matrix<i64> =
+        Matrix::from_iter(4, 4, vec![5, 0, 0, 1, 0, 0, 2, 0, 0, 2, 0, 0, 1, 0, 0, 7]);
+    let csr = CsrMatrix::from(&mat);
+    let csc = CscMatrix::from(&mat);
+
+    let mut out = Vec::new();
+    csr.write_matrix_market(
+        &mut out,
+        MatrixMarketField::Integer,
+        MatrixMarketSymmetry::General,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(out.clone()).unwrap(),
+        "%%MatrixMarket matrix coordinate integer general\n4 4 6\n\
+         1 1 5\n1 4 1\n2 3 2\n3 2 2\n4 1 1\n4 4 7\n"
+    );
+    let coo: CooMatrix<i64> = CooMatrix::read_matrix_market(&out[..]).unwrap();
+    assert_eq!(coo.to_csr(), csr);
+
+    let mut out = Vec::new();
+    csc.write_matrix_market(
+        &mut out,
+        MatrixMarketField::Integer,
+        MatrixMarketSymmetry::Symmetric,
+    )
+    .unwrap();
+    let coo: CooMatrix<i64> = CooMatrix::read_matrix_market(&out[..]).unwrap();
+    assert_eq!(coo.nnz(), 6);
+    assert_eq!(coo.to_csc(), csc);
+
+    let mut out = Vec::new();
+    coo.write_matrix_market(
+        &mut out,
+        MatrixMarketField::Pattern,
+        MatrixMarketSymmetry::General,
+    )
+    .unwrap();
+    let pattern: Matrix<i64> = Matrix::read_matrix_market(&out[..]).unwrap();
+    assert_eq!(
+        pattern,
+        Matrix::from_iter(4, 4, mat.iter().map(|&v| (v != 0) as i64))
+    );
+}
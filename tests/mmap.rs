@@ -0,0 +1,122 @@
+#![cfg(feature = "mmap")]
+
+use simple_matrix::{BinaryError, Matrix, MmapMatrix, MmapMatrixMut};
+use std::fs::{self, File};
+use std::path::PathBuf;
+
+/// Path of a temporary file, unique to the test.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("simple-matrix-{}-{}.smx", std::process::id(), name))
+}
+
+#[test]
+fn read_saved() {
+    let path = temp_path("read_saved");
+    let mat: Matrix<f64> = Matrix::from_iter(5, 7, (0..).map(f64::from));
+    mat.save(File::create(&path).unwrap()).unwrap();
+
+    let mapped: MmapMatrix<f64> = MmapMatrix::open(&path).unwrap();
+    assert_eq!(mapped.rows(), 5);
+    assert_eq!(mapped.cols(), 7);
+    assert_eq!(mapped.get(2, 3), Some(&17.0));
+    assert_eq!(mapped.get(5, 0), None);
+    assert_eq!(mapped[(4, 6)], 34.0);
+    assert_eq!(
+        mapped.get_row(1).unwrap().copied().collect::<Vec<_>>(),
+        mat.get_row(1).unwrap().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        mapped.get_col(6).unwrap().copied().collect::<Vec<_>>(),
+        mat.get_col(6).unwrap().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(mapped.iter().sum::<f64>(), mat.iter().sum::<f64>());
+    let tile = mapped.tile(1, 2, 2, 3);
+    assert_eq!((tile.rows(), tile.cols()), (2, 3));
+    assert_eq!(tile.get(1, 0), Some(&16.0));
+    assert_eq!(tile.get(2, 0), None);
+    assert_eq!(tile[(0, 2)], 11.0);
+    assert_eq!(tile.get_row(1), Some(&[16.0, 17.0, 18.0][..]));
+    assert_eq!(
+        tile.get_col(1).unwrap().copied().collect::<Vec<_>>(),
+        vec![10.0, 17.0]
+    );
+    assert_eq!(
+        tile.to_matrix(),
+        Matrix::from_iter(2, 3, vec![9.0, 10.0, 11.0, 16.0, 17.0, 18.0])
+    );
+    assert_eq!(mapped.to_matrix(), mat);
+    assert!(mapped.verify().is_ok());
+
+    drop(mapped);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn create_and_write() {
+    let path = temp_path("create_and_write");
+    {
+        let mut mat: MmapMatrixMut<i32> = MmapMatrixMut::create(&path, 4, 4).unwrap();
+        assert!(mat.iter().all(|&v| v == 0));
+
+        assert!(mat.set(0, 0, 1));
+        assert!(!mat.set(4, 0, 1));
+        mat[(3, 3)] = 2;
+        mat.set_tile(1, 1, &Matrix::from_iter(2, 2, vec![3, 4, 5, 6]));
+        mat.flush().unwrap();
+        assert!(mat.verify().is_ok());
+
+        // The checksum is updated when dropped
+        *mat.get_mut(0, 3).unwrap() = 7;
+    }
+
+    let expected = Matrix::from_iter(4, 4, vec![1, 0, 0, 7, 0, 3, 4, 0, 0, 5, 6, 0, 0, 0, 0, 2]);
+    let loaded: Matrix<i32> = Matrix::load(File::open(&path).unwrap()).unwrap();
+    assert_eq!(loaded, expected);
+
+    {
+        let mut mat: MmapMatrixMut<i32> = MmapMatrixMut::open(&path).unwrap();
+        mat.iter_mut().for_each(|v| *v *= 2);
+    }
+    let loaded: Matrix<i32> = Matrix::load(File::open(&path).unwrap()).unwrap();
+    assert_eq!(
+        loaded,
+        Matrix::from_iter(4, 4, expected.iter().map(|v| v * 2))
+    );
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn errors() {
+    let path = temp_path("errors");
+    let mat: Matrix<f32> = Matrix::from_iter(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    mat.save(File::create(&path).unwrap()).unwrap();
+
+    assert!(matches!(
+        MmapMatrix::<f64>::open(&path),
+        Err(BinaryError::TypeMismatch { .. })
+    ));
+
+    let mut bytes = fs::read(&path).unwrap();
+    bytes[33] ^= 0x01;
+    fs::write(&path, &bytes).unwrap();
+    let mapped: MmapMatrix<f32> = MmapMatrix::open(&path).unwrap();
+    assert!(matches!(
+        mapped.verify(),
+        Err(BinaryError::ChecksumMismatch { .. })
+    ));
+    drop(mapped);
+
+    bytes.pop();
+    fs::write(&path, &bytes).unwrap();
+    assert!(matches!(
+        MmapMatrix::<f32>::open(&path),
+        Err(BinaryError::InvalidHeader(_))
+    ));
+
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        MmapMatrix::<f32>::open(&path),
+        Err(BinaryError::Io(_))
+    ));
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "nalgebra")]
+
+use nalgebra::{dmatrix, DMatrix};
+use simple_matrix::Matrix;
+
+#[test]
+fn views() {
+    let mut mat: Matrix<f64> = Matrix::from_iter(2, 3, (1..).map(f64::from));
+
+    let view = mat.as_nalgebra();
+    assert_eq!(view, dmatrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0]);
+    assert_eq!(view.as_ptr(), mat.as_ptr());
+    assert_eq!(view.row_sum(), dmatrix![5.0, 7.0, 9.0]);
+
+    mat.as_nalgebra_mut().column_mut(2).fill(0.0);
+    assert_eq!(
+        mat,
+        Matrix::from_iter(2, 3, vec![1.0, 2.0, 0.0, 4.0, 5.0, 0.0])
+    );
+}
+
+#[test]
+fn conversions() {
+    let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+
+    let dmat: DMatrix<i32> = mat.clone().into();
+    assert_eq!(dmat, dmatrix![1, 2, 3; 4, 5, 6]);
+    assert_eq!(dmat.as_slice(), &[1, 4, 2, 5, 3, 6]);
+
+    let back: Matrix<i32> = dmat.clone().into();
+    assert_eq!(back, mat);
+
+    let from_view: Matrix<i32> = dmat.view((0, 1), (2, 2)).into();
+    assert_eq!(from_view, Matrix::from_iter(2, 2, vec![2, 3, 5, 6]));
+
+    let transposed: Matrix<i32> = mat.as_nalgebra().transpose().into();
+    assert_eq!(transposed, mat.transpose());
+}
+
+#[test]
+#[should_panic]
+fn empty() {
+    let _: Matrix<i32> = DMatrix::<i32>::zeros(2, 0).into();
+}
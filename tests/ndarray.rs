@@ -0,0 +1,54 @@
+#![cfg(feature = "ndarray")]
+
+use ndarray::{arr2, s, Array2, ShapeBuilder};
+use simple_matrix::Matrix;
+
+#[test]
+fn views() {
+    let mut mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+
+    let view = mat.as_ndarray();
+    assert_eq!(view, arr2(&[[1, 2, 3], [4, 5, 6]]));
+    assert_eq!(view.as_ptr(), mat.as_ptr());
+
+    mat.as_ndarray_mut().column_mut(1).fill(0);
+    assert_eq!(mat, Matrix::from_iter(2, 3, vec![1, 0, 3, 4, 0, 6]));
+}
+
+#[test]
+fn conversions() {
+    let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+
+    let array: Array2<i32> = mat.clone().into();
+    assert_eq!(array, arr2(&[[1, 2, 3], [4, 5, 6]]));
+
+    // Standard layout arrays are moved without copying
+    let back: Matrix<i32> = array.into();
+    assert_eq!(back, mat);
+
+    let array: Array2<i32> = mat.clone().into();
+    let ptr = array.as_ptr();
+    let back: Matrix<i32> = array.into();
+    assert_eq!(back.as_ptr(), ptr);
+
+    let transposed: Matrix<i32> = mat.as_ndarray().t().to_owned().into();
+    assert_eq!(transposed, mat.transpose());
+
+    let fortran = Array2::from_shape_vec((2, 2).f(), vec![1, 2, 3, 4]).unwrap();
+    let from_fortran: Matrix<i32> = fortran.into();
+    assert_eq!(from_fortran, Matrix::from_iter(2, 2, vec![1, 3, 2, 4]));
+
+    let mut sliced = arr2(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    sliced.slice_collapse(s![1.., ..]);
+    let from_sliced: Matrix<i32> = sliced.into();
+    assert_eq!(from_sliced, Matrix::from_iter(2, 3, 4..));
+
+    let from_view: Matrix<i32> = mat.as_ndarray().slice(s![.., 1..]).into();
+    assert_eq!(from_view, Matrix::from_iter(2, 2, vec![2, 3, 5, 6]));
+}
+
+#[test]
+#[should_panic]
+fn empty() {
+    let _: Matrix<i32> = Array2::<i32>::zeros((0, 3)).into();
+}
@@ -0,0 +1,187 @@
+use simple_matrix::{Matrix, NpyError};
+
+/// Build a version 1.0 `.npy` file with the given header dictionary and data.
+fn npy(dict: &str, data: &[u8]) -> Vec<u8> {
+    let mut header = dict.to_string();
+    header.push('\n');
+    let mut out = b"\x93NUMPY\x01\x00".to_vec();
+    out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    out.extend_from_slice(header.as_bytes());
+    out.extend_from_slice(data);
+    out
+}
+
+#[test]
+fn read_c_order() {
+    let data: Vec<u8> = [1.5f32, 2.0, 3.0, -4.0, 5.0, 6.25]
+        .iter()
+        .flat_map(|v| v.to_le_bytes().to_vec())
+        .collect();
+    let file = npy(
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }",
+        &data,
+    );
+
+    let mat: Matrix<f32> = Matrix::read_npy(&file[..]).unwrap();
+    assert_eq!(
+        mat,
+        Matrix::from_iter(2, 3, vec![1.5, 2.0, 3.0, -4.0, 5.0, 6.25])
+    );
+}
+
+#[test]
+fn read_fortran_order_big_endian() {
+    let data: Vec<u8> = [1i32, 4, 2, 5, 3, 6]
+        .iter()
+        .flat_map(|v| v.to_be_bytes().to_vec())
+        .collect();
+    let file = npy(
+        "{'descr': '>i4', 'fortran_order': True, 'shape': (2, 3), }",
+        &data,
+    );
+
+    let mat: Matrix<i32> = Matrix::read_npy(&file[..]).unwrap();
+    assert_eq!(mat, Matrix::from_iter(2, 3, vec![1, 2, 3, 4, 5, 6]));
+}
+
+#[test]
+fn read_version_2() {
+    let header = "{'shape': (1, 2), 'fortran_order': False, 'descr': '|u1'}\n";
+    let mut file = b"\x93NUMPY\x02\x00".to_vec();
+    file.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    file.extend_from_slice(header.as_bytes());
+    file.extend_from_slice(&[7, 255]);
+
+    let mat: Matrix<u8> = Matrix::read_npy(&file[..]).unwrap();
+    assert_eq!(mat, Matrix::from_iter(1, 2, vec![7, 255]));
+}
+
+#[test]
+fn write_layout() {
+    let mat: Matrix<u16> = Matrix::from_iter(2, 2, vec![1, 2, 3, 0x0102]);
+    let mut out = Vec::new();
+    mat.write_npy(&mut out).unwrap();
+
+    assert_eq!(&out[..8], b"\x93NUMPY\x01\x00");
+    let header_len = usize::from(u16::from_le_bytes([out[8], out[9]]));
+    assert_eq!((10 + header_len) % 64, 0);
+    let header = std::str::from_utf8(&out[10..10 + header_len]).unwrap();
+    assert!(header.starts_with("{'descr': '<u2', 'fortran_order': False, 'shape': (2, 2), }"));
+    assert!(header.ends_with('\n'));
+    assert_eq!(&out[10 + header_len..], &[1, 0, 2, 0, 3, 0, 2, 1]);
+}
+
+#[test]
+fn round_trip() {
+    let floats: Matrix<f64> = Matrix::from_iter(3, 2, vec![0.1, -2.5, 1e300, 0.0, 4.0, -0.0]);
+    let mut out = Vec::new();
+    floats.write_npy(&mut out).unwrap();
+    assert_eq!(Matrix::<f64>::read_npy(&out[..]).unwrap(), floats);
+
+    let bools = Matrix::from_iter(2, 2, vec![true, false, false, true]);
+    let mut out = Vec::new();
+    bools.write_npy(&mut out).unwrap();
+    assert_eq!(Matrix::<bool>::read_npy(&out[..]).unwrap(), bools);
+
+    let longs: Matrix<i64> = Matrix::from_iter(1, 3, vec![i64::MIN, 0, i64::MAX]);
+    let mut out = Vec::new();
+    longs.write_npy(&mut out).unwrap();
+    assert_eq!(Matrix::<i64>::read_npy(&out[..]).unwrap(), longs);
+}
+
+#[test]
+fn read_errors() {
+    let f8 = npy(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 1), }",
+        &1.0f64.to_le_bytes(),
+    );
+    match Matrix::<f32>::read_npy(&f8[..]) {
+        Err(NpyError::DtypeMismatch { expected, found }) => {
+            assert_eq!(expected, "<f4");
+            assert_eq!(found, "<f8");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let vector = npy(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }",
+        &[0; 24],
+    );
+    match Matrix::<f64>::read_npy(&vector[..]) {
+        Err(NpyError::InvalidShape(shape)) => assert_eq!(shape, vec![3]),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let truncated = npy(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }",
+        &[0; 24],
+    );
+    assert!(matches!(
+        Matrix::<f64>::read_npy(&truncated[..]),
+        Err(NpyError::Io(_))
+    ));
+
+    // Sizes out of reach are not allocated up front
+    let huge = npy(
+        "{'descr': '<f8', 'fortran_order': False, 'shape': (1000000, 1000000), }",
+        &[0; 24],
+    );
+    assert!(matches!(
+        Matrix::<f64>::read_npy(&huge[..]),
+        Err(NpyError::Io(_))
+    ));
+
+    let overflowing = npy(
+        &format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, 2), }}",
+            usize::MAX
+        ),
+        &[0; 24],
+    );
+    assert!(matches!(
+        Matrix::<f64>::read_npy(&overflowing[..]),
+        Err(NpyError::InvalidHeader(_))
+    ));
+
+    let no_shape = npy("{'descr': '<f8', 'fortran_order': False, }", &[]);
+    assert!(matches!(
+        Matrix::<f64>::read_npy(&no_shape[..]),
+        Err(NpyError::InvalidHeader(_))
+    ));
+
+    assert!(matches!(
+        Matrix::<f64>::read_npy(&b"PK\x03\x04 not a npy file"[..]),
+        Err(NpyError::InvalidHeader(_))
+    ));
+}
+
+#[cfg(feature = "npz")]
+#[test]
+fn read_npz() {
+    use std::io::{Cursor, Write};
+
+    let weights: Matrix<f32> = Matrix::from_iter(2, 2, vec![0.5, -1.0, 2.0, 3.5]);
+    let bias: Matrix<f32> = Matrix::from_iter(1, 2, vec![0.25, 0.75]);
+
+    let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, mat, method) in &[
+        ("weights.npy", &weights, zip::CompressionMethod::Stored),
+        ("bias.npy", &bias, zip::CompressionMethod::Deflated),
+    ] {
+        let options = zip::write::FileOptions::default().compression_method(*method);
+        archive.start_file(*name, options).unwrap();
+        let mut out = Vec::new();
+        mat.write_npy(&mut out).unwrap();
+        archive.write_all(&out).unwrap();
+    }
+    let bytes = archive.finish().unwrap().into_inner();
+
+    let read = |name| Matrix::<f32>::read_npz(Cursor::new(&bytes), name);
+    assert_eq!(read("weights").unwrap(), weights);
+    assert_eq!(read("bias.npy").unwrap(), bias);
+    assert!(matches!(read("missing"), Err(NpyError::MissingArray(_))));
+    assert!(matches!(
+        Matrix::<f32>::read_npz(Cursor::new(&b"not a zip"[..]), "weights"),
+        Err(NpyError::InvalidArchive(_))
+    ));
+}
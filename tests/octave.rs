@@ -0,0 +1,81 @@
+use simple_matrix::{Matrix, ParseMatrixError};
+
+#[test]
+fn parse() {
+    let expected: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+
+    assert_eq!("[1 2 3; 4 5 6]".parse::<Matrix<i32>>().unwrap(), expected);
+    assert_eq!(
+        "[1, 2, 3; 4, 5, 6;]".parse::<Matrix<i32>>().unwrap(),
+        expected
+    );
+    assert_eq!(
+        "  1 2 3\n\n4\t5  6\n".parse::<Matrix<i32>>().unwrap(),
+        expected
+    );
+    assert_eq!(
+        "[\n  1 2 3\n  4 5 6\n]".parse::<Matrix<i32>>().unwrap(),
+        expected
+    );
+    assert_eq!(
+        "1 2 3\r\n4 5 6\r\n".parse::<Matrix<i32>>().unwrap(),
+        expected
+    );
+    assert_eq!(
+        "[-1.5e3]".parse::<Matrix<f64>>().unwrap(),
+        Matrix::from_iter(1, 1, vec![-1500.0])
+    );
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        "[1 2; 3]".parse::<Matrix<i32>>(),
+        Err(ParseMatrixError::RaggedRow {
+            row: 2,
+            expected: 2,
+            found: 1,
+        })
+    );
+    assert_eq!(
+        "[1 2; 3 x]".parse::<Matrix<i32>>(),
+        Err(ParseMatrixError::InvalidElement {
+            row: 2,
+            col: 2,
+            element: "x".to_string(),
+            message: "invalid digit found in string".to_string(),
+        })
+    );
+    assert_eq!(
+        "[1 2".parse::<Matrix<i32>>(),
+        Err(ParseMatrixError::UnbalancedBrackets)
+    );
+    assert_eq!(
+        "[[1 2]]".parse::<Matrix<i32>>(),
+        Err(ParseMatrixError::UnbalancedBrackets)
+    );
+    assert_eq!("[ ; ]".parse::<Matrix<i32>>(), Err(ParseMatrixError::Empty));
+    assert_eq!("".parse::<Matrix<i32>>(), Err(ParseMatrixError::Empty));
+}
+
+#[test]
+fn round_trip() {
+    let ints: Matrix<i64> = Matrix::from_iter(3, 2, vec![-1, 0, 1, i64::MAX, i64::MIN, 7]);
+    assert_eq!(
+        ints.to_octave_string(),
+        format!("[-1 0; 1 {}; {} 7]", i64::MAX, i64::MIN)
+    );
+    assert_eq!(
+        ints.to_octave_string().parse::<Matrix<i64>>().unwrap(),
+        ints
+    );
+
+    let floats: Matrix<f64> = Matrix::from_iter(2, 2, vec![0.1, 1.0 / 3.0, -2.5e-300, 1e300]);
+    assert_eq!(
+        floats.to_octave_string().parse::<Matrix<f64>>().unwrap(),
+        floats
+    );
+
+    let row: Matrix<u8> = Matrix::from_iter(1, 3, vec![1, 2, 3]);
+    assert_eq!(row.to_octave_string(), "[1 2 3]");
+}
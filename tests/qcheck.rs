@@ -1,7 +1,8 @@
 use quickcheck::{quickcheck, Arbitrary, Gen};
-use simple_matrix::Matrix;
+use simple_matrix::{Matrix, Norm};
 
 const RANGE: i32 = 100000; // No over/under-flow checking for now
+const TOL: f64 = 1e-9;
 
 #[derive(Debug, Clone)]
 struct AMatrix<T>(Matrix<T>);
@@ -56,6 +57,20 @@ impl Arbitrary for A3Matrix<i32> {
     }
 }
 
+impl Arbitrary for A2Matrix<f64> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let s = std::cmp::max(2, g.size() / 4); // rows & cols != 0
+
+        let cols = g.gen_range(1, s);
+        let rows = g.gen_range(1, s);
+
+        A2Matrix(
+            Matrix::from_iter(rows, cols, (0..).map(|_| g.gen_range(-100.0, 100.0))),
+            Matrix::from_iter(rows, cols, (0..).map(|_| g.gen_range(-100.0, 100.0))),
+        )
+    }
+}
+
 fn neg(m: Matrix<i32>) -> Matrix<i32> {
     let zero = Matrix::new(m.rows(), m.cols());
     zero - m
@@ -280,6 +295,16 @@ quickcheck! {
         true
     }
 
+    fn qcheck_transpose(t: AMatrix<i32>) -> bool {
+        let a = &t.0;
+        let a_t = a.transpose();
+
+        (a_t.rows() == a.cols())
+        && (a_t.cols() == a.rows())
+        && (0..a.rows()).all(|r| (0..a.cols()).all(|c| a[(r, c)] == a_t[(c, r)]))
+        && (&a_t.transpose() == a)
+    }
+
     fn qcheck_add(t: A3Matrix<i32>) -> bool {
         let a = &t.0;
         let b = &t.1;
@@ -312,4 +337,31 @@ quickcheck! {
         && (&(a * ident1) == a)
         && (&(ident2 * a) == a)
     }
+
+    fn qcheck_norms(t: A2Matrix<f64>) -> bool {
+        let a = &t.0;
+        let b = &t.1;
+        let a_t = a.transpose();
+        let fro = a.norm(Norm::Frobenius);
+        let spectral = a.norm(Norm::Spectral);
+        let norms = [Norm::Frobenius, Norm::One, Norm::Inf, Norm::Max, Norm::Spectral];
+
+        (a.norm(Norm::One) == a_t.norm(Norm::Inf))
+        && (fro - (&a_t * a).trace().sqrt()).abs() <= TOL * fro
+        && spectral <= fro * (1.0 + TOL)
+        && a.norm(Norm::Max) <= spectral * (1.0 + TOL)
+        && a.distance(&Matrix::new(a.rows(), a.cols()), Norm::Inf) == a.norm(Norm::Inf)
+        && norms.iter().all(|&n| (a + b).norm(n) <= (a.norm(n) + b.norm(n)) * (1.0 + TOL))
+    }
+}
+
+#[test]
+fn transpose_non_square() {
+    let a: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+
+    assert_eq!(
+        a.transpose(),
+        Matrix::from_iter(3, 2, vec![1, 4, 2, 5, 3, 6])
+    );
+    assert_eq!(a.transpose().transpose(), a);
 }
@@ -0,0 +1,95 @@
+use simple_matrix::{Colormap, Matrix, RenderOptions, ValueRange};
+
+#[test]
+fn colormaps() {
+    assert_eq!(Colormap::Viridis.color(0.0), [68, 1, 84]);
+    assert_eq!(Colormap::Viridis.color(1.0), [253, 231, 37]);
+    assert_eq!(Colormap::Viridis.color(0.0625), [70, 21, 102]);
+    assert_eq!(Colormap::Grayscale.color(0.5), [128, 128, 128]);
+    assert_eq!(Colormap::Diverging.color(0.5), [247, 247, 247]);
+    assert_eq!(Colormap::Diverging.color(-3.0), [59, 76, 192]);
+    assert_eq!(Colormap::Diverging.color(3.0), [180, 4, 38]);
+    assert_eq!(
+        Colormap::Custom(|t| [0, (t * 100.0) as u8, 0]).color(0.5),
+        [0, 50, 0]
+    );
+}
+
+#[test]
+fn pgm() {
+    let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![0.0, 1.0, 2.0, f64::NAN]);
+    let mut out = Vec::new();
+    mat.write_pgm(&mut out, &RenderOptions::default()).unwrap();
+
+    let mut expected = b"P5\n2 2\n255\n".to_vec();
+    expected.extend_from_slice(&[0, 128, 255, 0]);
+    assert_eq!(out, expected);
+
+    let options = RenderOptions {
+        range: ValueRange::Fixed(0.0, 1.0),
+        cell_size: 2,
+        ..RenderOptions::default()
+    };
+    let mut out = Vec::new();
+    mat.write_pgm(&mut out, &options).unwrap();
+
+    let mut expected = b"P5\n4 4\n255\n".to_vec();
+    expected.extend_from_slice(&[0, 0, 255, 255, 0, 0, 255, 255]);
+    expected.extend_from_slice(&[255, 255, 0, 0, 255, 255, 0, 0]);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn ppm() {
+    let mat: Matrix<f32> = Matrix::from_iter(1, 3, vec![-2.0, 0.0, 1.0]);
+    let options = RenderOptions {
+        colormap: Colormap::Diverging,
+        range: ValueRange::Symmetric,
+        cell_size: 1,
+    };
+    let mut out = Vec::new();
+    mat.write_ppm(&mut out, &options).unwrap();
+
+    let mut expected = b"P6\n3 1\n255\n".to_vec();
+    expected.extend_from_slice(&[59, 76, 192, 247, 247, 247]);
+    expected.extend_from_slice(&Colormap::Diverging.color(0.75));
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn svg() {
+    let mat: Matrix<f64> = Matrix::from_iter(1, 2, vec![0.0, 1.0]);
+    let options = RenderOptions {
+        cell_size: 10,
+        ..RenderOptions::default()
+    };
+    let mut out = Vec::new();
+    mat.write_svg(&mut out, &options).unwrap();
+    let svg = String::from_utf8(out).unwrap();
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"20\" height=\"10\""));
+    assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"10\" height=\"10\" fill=\"#440154\"/>"));
+    assert!(svg.contains("<rect x=\"10\" y=\"0\" width=\"10\" height=\"10\" fill=\"#fde725\"/>"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn heatmap() {
+    let mat: Matrix<f64> = Matrix::from_iter(2, 1, vec![3.0, 3.0]);
+    let options = RenderOptions {
+        colormap: Colormap::Grayscale,
+        ..RenderOptions::default()
+    };
+
+    // A constant matrix is mapped to the middle of the colormap
+    let line = "\x1b[48;2;128;128;128m  \x1b[0m\n";
+    assert_eq!(mat.heatmap(&options), format!("{}{}", line, line));
+}
+
+#[test]
+fn spy() {
+    let mat: Matrix<f64> =
+        Matrix::from_iter(3, 3, vec![1.0, 0.0, 0.0, 0.0, 2.0, -1.0, 0.0, 0.0, 3.0]);
+
+    assert_eq!(mat.spy(), "● · ·\n· ● ●\n· · ●\n");
+}
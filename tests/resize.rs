@@ -0,0 +1,93 @@
+use simple_matrix::Matrix;
+
+#[test]
+fn insert() {
+    let mut mat: Matrix<i32> = Matrix::from_iter(1, 2, vec![3, 4]);
+
+    mat.insert_row(0, vec![1, 2]);
+    mat.push_row(5..);
+    assert_eq!(mat, Matrix::from_iter(3, 2, vec![1, 2, 3, 4, 5, 6]));
+
+    mat.insert_col(0, vec![0, 0, 0]);
+    mat.insert_col(2, vec![7, 8, 9]);
+    mat.push_col(std::iter::repeat(-1));
+    assert_eq!(
+        mat,
+        Matrix::from_iter(3, 5, vec![0, 1, 7, 2, -1, 0, 3, 8, 4, -1, 0, 5, 9, 6, -1])
+    );
+}
+
+#[test]
+fn remove() {
+    let mut mat: Matrix<String> = Matrix::from_iter(3, 3, (1..).map(|n| n.to_string()));
+
+    assert_eq!(mat.remove_row(2), vec!["7", "8", "9"]);
+    assert_eq!(mat.remove_col(1), vec!["2", "5"]);
+    assert_eq!(mat.remove_col(1), vec!["3", "6"]);
+    assert_eq!((mat.rows(), mat.cols()), (2, 1));
+    assert_eq!(mat.remove_row(0), vec!["1"]);
+    assert_eq!(mat, Matrix::from_iter(1, 1, vec!["4".to_string()]));
+}
+
+#[test]
+fn resize() {
+    let mut mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+
+    mat.resize(3, 4, 0);
+    assert_eq!(
+        mat,
+        Matrix::from_iter(3, 4, vec![1, 2, 3, 0, 4, 5, 6, 0, 0, 0, 0, 0])
+    );
+
+    mat.resize(2, 2, 9);
+    assert_eq!(mat, Matrix::from_iter(2, 2, vec![1, 2, 4, 5]));
+
+    mat.resize(1, 3, 9);
+    assert_eq!(mat, Matrix::from_iter(1, 3, vec![1, 2, 9]));
+
+    mat.resize(2, 3, 7);
+    assert_eq!(mat, Matrix::from_iter(2, 3, vec![1, 2, 9, 7, 7, 7]));
+}
+
+#[test]
+fn truncate() {
+    let mut mat: Matrix<i32> = Matrix::from_iter(3, 4, 1..);
+
+    mat.truncate(5, 3);
+    assert_eq!(
+        mat,
+        Matrix::from_iter(3, 3, vec![1, 2, 3, 5, 6, 7, 9, 10, 11])
+    );
+
+    mat.truncate(2, 2);
+    assert_eq!(mat, Matrix::from_iter(2, 2, vec![1, 2, 5, 6]));
+}
+
+#[test]
+fn retain_rows() {
+    let mut mat: Matrix<i32> = Matrix::from_iter(4, 2, 1..);
+
+    mat.retain_rows(|row| row[0] % 4 != 1);
+    assert_eq!(mat, Matrix::from_iter(2, 2, vec![3, 4, 7, 8]));
+}
+
+#[test]
+#[should_panic]
+fn retain_no_rows() {
+    let mut mat: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    mat.retain_rows(|_| false);
+}
+
+#[test]
+#[should_panic]
+fn remove_last_row() {
+    let mut mat: Matrix<i32> = Matrix::from_iter(1, 3, 1..);
+    mat.remove_row(0);
+}
+
+#[test]
+#[should_panic]
+fn short_row() {
+    let mut mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    mat.push_row(vec![1, 2]);
+}
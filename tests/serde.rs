@@ -0,0 +1,75 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use simple_matrix::Matrix;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Layer {
+    #[serde(with = "simple_matrix::nested_rows")]
+    weights: Matrix<i32>,
+    bias: Matrix<i32>,
+}
+
+#[test]
+fn json() {
+    let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    let json = serde_json::to_string(&mat).unwrap();
+
+    assert_eq!(json, r#"{"rows":2,"cols":3,"data":[1,2,3,4,5,6]}"#);
+    assert_eq!(serde_json::from_str::<Matrix<i32>>(&json).unwrap(), mat);
+}
+
+#[test]
+fn json_validation() {
+    let cases = [
+        r#"{"rows":2,"cols":3,"data":[1,2,3,4,5]}"#,
+        r#"{"rows":0,"cols":3,"data":[]}"#,
+        r#"{"rows":2,"data":[1,2]}"#,
+        r#"{"rows":1,"cols":2,"data":[1,"2"]}"#,
+    ];
+    for json in &cases {
+        assert!(
+            serde_json::from_str::<Matrix<i32>>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+
+    let err = serde_json::from_str::<Matrix<i32>>(cases[0]).unwrap_err();
+    assert!(err.to_string().contains("expected 6 cells"));
+}
+
+#[test]
+fn nested_rows() {
+    let layer = Layer {
+        weights: Matrix::from_iter(2, 2, vec![1, 2, 3, 4]),
+        bias: Matrix::from_iter(1, 2, vec![5, 6]),
+    };
+    let json = serde_json::to_string(&layer).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"weights":[[1,2],[3,4]],"bias":{"rows":1,"cols":2,"data":[5,6]}}"#
+    );
+    assert_eq!(serde_json::from_str::<Layer>(&json).unwrap(), layer);
+
+    let bias = r#""bias":{"rows":1,"cols":1,"data":[0]}"#;
+    for weights in &["[[1,2],[3]]", "[]", "[[]]"] {
+        let json = format!(r#"{{"weights":{},{}}}"#, weights, bias);
+        assert!(serde_json::from_str::<Layer>(&json).is_err(), "{}", json);
+    }
+}
+
+#[test]
+fn bincode() {
+    let mat: Matrix<f64> = Matrix::from_iter(3, 2, vec![0.5, -1.0, 2.25, 1e10, 0.0, 3.0]);
+    let bytes = bincode::serialize(&mat).unwrap();
+    assert_eq!(bincode::deserialize::<Matrix<f64>>(&bytes).unwrap(), mat);
+
+    let layer = Layer {
+        weights: Matrix::from_iter(1, 3, vec![1, 2, 3]),
+        bias: Matrix::from_iter(1, 1, vec![4]),
+    };
+    let bytes = bincode::serialize(&layer).unwrap();
+    assert_eq!(bincode::deserialize::<Layer>(&bytes).unwrap(), layer);
+}
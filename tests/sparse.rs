@@ -0,0 +1,162 @@
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use simple_matrix::iterative::{cg, LinearOperator, Settings};
+use simple_matrix::{CooMatrix, CscMatrix, CsrMatrix, Matrix};
+
+/// Three sparse matrices, so that `a * b` and `a + c` are defined.
+#[derive(Debug, Clone)]
+struct ASparse(Matrix<i64>, Matrix<i64>, Matrix<i64>);
+
+impl Arbitrary for ASparse {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let s = std::cmp::max(2, g.size() / 2); // rows & cols != 0
+
+        let rows = g.gen_range(1, s);
+        let inner = g.gen_range(1, s);
+        let cols = g.gen_range(1, s);
+        let mut sparse = |rows, cols| {
+            Matrix::from_iter(
+                rows,
+                cols,
+                (0..).map(|_| {
+                    if g.gen_range(0, 5) == 0 {
+                        g.gen_range(-100, 100)
+                    } else {
+                        0
+                    }
+                }),
+            )
+        };
+
+        ASparse(
+            sparse(rows, inner),
+            sparse(inner, cols),
+            sparse(rows, inner),
+        )
+    }
+}
+
+#[test]
+fn coo_duplicates() {
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(1, 2, 4);
+    coo.push(0, 1, 1);
+    coo.push(1, 2, -1);
+    coo.push(1, 0, 5);
+    coo.push(1, 0, -5);
+
+    let dense = Matrix::from_iter(2, 3, vec![0, 1, 0, 0, 0, 3]);
+    assert_eq!(coo.nnz(), 5);
+    assert_eq!(coo.to_dense(), dense);
+
+    // Duplicates are summed, and cancelled entries dropped
+    let csr = coo.to_csr();
+    assert_eq!(csr.row_offsets(), &[0, 1, 2]);
+    assert_eq!(csr.col_indices(), &[1, 2]);
+    assert_eq!(csr.values(), &[1, 3]);
+    assert_eq!(csr.get_row(1).unwrap().collect::<Vec<_>>(), vec![(2, &3)]);
+    assert!(csr.get_row(2).is_none());
+
+    let csc = coo.to_csc();
+    assert_eq!(csc.col_offsets(), &[0, 0, 1, 2]);
+    assert_eq!(csc.row_indices(), &[0, 1]);
+    assert_eq!(csc.get_col(0).unwrap().count(), 0);
+    assert!(csc.get_col(3).is_none());
+
+    assert_eq!(
+        csr,
+        CsrMatrix::from_parts(2, 3, vec![0, 1, 2], vec![1, 2], vec![1, 3])
+    );
+    assert_eq!(
+        csc,
+        CscMatrix::from_parts(2, 3, vec![0, 0, 1, 2], vec![0, 1], vec![1, 3])
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_parts_unsorted() {
+    CsrMatrix::from_parts(1, 3, vec![0, 2], vec![2, 1], vec![1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn from_parts_out_of_bounds() {
+    CscMatrix::from_parts(2, 1, vec![0, 1], vec![2], vec![1]);
+}
+
+#[test]
+fn sparse_cg() {
+    let n = 100;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0f64);
+        if i > 0 {
+            coo.push(i, i - 1, -1.0);
+            coo.push(i - 1, i, -1.0);
+        }
+    }
+    let csr = coo.to_csr();
+    assert_eq!(csr.nnz(), 3 * n - 2);
+
+    let b = vec![1.0; n];
+    let solution = cg(&csr, &b, &Settings::default());
+    assert!(solution.converged);
+
+    let mut ax = vec![0.0; n];
+    coo.to_csc().mul_vec(&solution.x, &mut ax);
+    assert!(ax.iter().zip(&b).all(|(ax, b)| (ax - b).abs() < 1e-6));
+}
+
+quickcheck! {
+    fn qcheck_conversions(t: ASparse) -> bool {
+        let a = &t.0;
+        let coo = CooMatrix::from(a);
+        let csr = CsrMatrix::from(a);
+        let csc = CscMatrix::from(a);
+        let nnz = a.iter().filter(|&&v| v != 0).count();
+
+        coo.to_dense() == *a
+        && csr.to_dense() == *a
+        && csc.to_dense() == *a
+        && csr.nnz() == nnz
+        && csc.nnz() == nnz
+        && coo.to_csr() == csr
+        && csr.to_csc() == csc
+        && csc.to_csr() == csr
+        && csr.to_coo().to_csc() == csc
+        && csr.iter().all(|(row, col, &v)| a[(row, col)] == v)
+        && csc.iter().all(|(row, col, &v)| a[(row, col)] == v)
+    }
+
+    fn qcheck_transpose(t: ASparse) -> bool {
+        let a = &t.0;
+        let at = a.transpose();
+
+        CooMatrix::from(a).transpose().to_dense() == at
+        && CsrMatrix::from(a).transpose() == CsrMatrix::from(&at)
+        && CscMatrix::from(a).transpose() == CscMatrix::from(&at)
+    }
+
+    fn qcheck_add(t: ASparse) -> bool {
+        let (a, c) = (&t.0, &t.2);
+        let sum = a + c;
+
+        (&CooMatrix::from(a) + &CooMatrix::from(c)).to_dense() == sum
+        && &CsrMatrix::from(a) + &CsrMatrix::from(c) == CsrMatrix::from(&sum)
+        && &CscMatrix::from(a) + &CscMatrix::from(c) == CscMatrix::from(&sum)
+    }
+
+    fn qcheck_mul(t: ASparse) -> bool {
+        let (a, b) = (&t.0, &t.1);
+        let prod = a * b;
+
+        let mut x = vec![0; a.rows()];
+        CsrMatrix::from(a).mul_vec(&b.get_col(0).unwrap().cloned().collect::<Vec<_>>(), &mut x);
+
+        &CsrMatrix::from(a) * b == prod
+        && &CscMatrix::from(a) * b == prod
+        && &CsrMatrix::from(a) * &CsrMatrix::from(b) == CsrMatrix::from(&prod)
+        && &CscMatrix::from(a) * &CscMatrix::from(b) == CscMatrix::from(&prod)
+        && x.iter().eq(prod.get_col(0).unwrap())
+    }
+}
@@ -0,0 +1,114 @@
+use simple_matrix::special::*;
+use simple_matrix::Matrix;
+
+fn identity(len: usize) -> Matrix<f64> {
+    Matrix::from_iter(
+        len,
+        len,
+        (0..).map(|i| if i % (len + 1) == 0 { 1.0 } else { 0.0 }),
+    )
+}
+
+fn is_orthogonal(mat: &Matrix<f64>) -> bool {
+    (&mat.transpose() * mat).approx_eq(&identity(mat.rows()), 1e-14, 0.0)
+}
+
+#[test]
+fn structure() {
+    let col = [1, 2, 3, 4];
+    let row = [1, 5, 6];
+
+    let t = toeplitz(&col, &row);
+    assert_eq!((t.rows(), t.cols()), (4, 3));
+    assert!((1..4).all(|i| (1..3).all(|j| t[(i, j)] == t[(i - 1, j - 1)])));
+
+    let h = hankel(&col, &[4, 5, 6]);
+    assert_eq!(
+        h,
+        Matrix::from_iter(4, 3, vec![1, 2, 3, 2, 3, 4, 3, 4, 5, 4, 5, 6])
+    );
+
+    let c = circulant(&col);
+    assert_eq!(c.get_col(0).unwrap().cloned().collect::<Vec<_>>(), col);
+    assert_eq!(
+        c.get_col(1).unwrap().cloned().collect::<Vec<_>>(),
+        vec![4, 1, 2, 3]
+    );
+    // Circulant matrices commute
+    let d = circulant(&[0, -1, 7, 2]);
+    assert_eq!(&c * &d, &d * &c);
+
+    let v = vandermonde(&[2i64, -1, 3, 5], 4);
+    // Product of the pairwise differences of the points
+    assert_eq!(v.determinant(), Some(-3 * 3 * 4 * 6 * 2));
+    assert_eq!(
+        v.get_row(3).unwrap().cloned().collect::<Vec<_>>(),
+        vec![1, 5, 25, 125]
+    );
+
+    let b = block_diagonal(&[t.clone(), c.clone()]);
+    assert_eq!((b.rows(), b.cols()), (8, 7));
+    assert_eq!(b[(5, 4)], c[(1, 1)]);
+    assert_eq!(b[(5, 1)], 0);
+    assert_eq!(b[(1, 5)], 0);
+}
+
+#[test]
+fn pascal_hilbert() {
+    let p: Matrix<i64> = pascal(8);
+    assert_eq!(p.determinant(), Some(1));
+    assert_eq!(p[(7, 7)], 3432);
+    assert_eq!(p, p.transpose());
+
+    let h: Matrix<f64> = hilbert(3);
+    assert_eq!(h[(0, 0)], 1.0);
+    assert_eq!(h[(2, 1)], 0.25);
+    assert_eq!(h, h.transpose());
+    assert!(hilbert::<f64>(8).condition_number() > 1e10);
+}
+
+#[test]
+fn companion_roots() {
+    // (x - 1)(x + 2)(x - 3) = x³ - 2x² - 5x + 6
+    let coeffs = [1, -2, -5, 6];
+    let c = companion(&coeffs);
+
+    // The matrix is a root of its characteristic polynomial
+    let mut p = Matrix::from_iter(3, 3, (0..).map(|i| if i % 4 == 0 { coeffs[0] } else { 0 }));
+    for &a in &coeffs[1..] {
+        p = &p * &c;
+        for i in 0..3 {
+            p[(i, i)] += a;
+        }
+    }
+    assert_eq!(p, Matrix::new(3, 3));
+
+    // Non-monic polynomials are normalized
+    assert_eq!(
+        companion(&[2.0, 4.0, -6.0]),
+        Matrix::from_iter(2, 2, vec![-2.0, 3.0, 1.0, 0.0])
+    );
+}
+
+#[test]
+fn reflections_rotations() {
+    let v = [3.0, -1.0, 2.0, 0.5];
+    let h = householder(&v);
+    assert!(is_orthogonal(&h));
+    assert_eq!(h, h.transpose());
+
+    // Reflects `v` onto `-v`
+    let hv = &h * &Matrix::from_iter(4, 1, v.iter().cloned());
+    assert!(hv.approx_eq(&Matrix::from_iter(4, 1, v.iter().map(|x| -x)), 1e-14, 0.0));
+
+    let g: Matrix<f64> = givens(4, 1, 3, 0.3);
+    assert!(is_orthogonal(&g));
+    assert_eq!(g[(1, 1)], 0.3f64.cos());
+    assert_eq!(g[(1, 3)], 0.3f64.sin());
+    assert_eq!(g[(3, 1)], -0.3f64.sin());
+    assert_eq!(g[(0, 0)], 1.0);
+
+    // Rotations of opposite angles cancel out
+    let back: Matrix<f64> = givens(4, 1, 3, -0.3);
+    assert!((&g * &back).approx_eq(&identity(4), 1e-15, 0.0));
+}
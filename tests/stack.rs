@@ -0,0 +1,78 @@
+use simple_matrix::{Axis, Matrix};
+
+#[test]
+fn stack() {
+    let a: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    let b: Matrix<i32> = Matrix::from_iter(2, 1, vec![7, 8]);
+    let c: Matrix<i32> = Matrix::from_iter(1, 2, vec![9, 10]);
+
+    assert_eq!(
+        Matrix::hstack(&[&a, &b, &a]),
+        Matrix::from_iter(2, 5, vec![1, 2, 7, 1, 2, 3, 4, 8, 3, 4])
+    );
+    assert_eq!(
+        Matrix::vstack(&[&c, &a]),
+        Matrix::from_iter(3, 2, vec![9, 10, 1, 2, 3, 4])
+    );
+    assert_eq!(
+        Matrix::concat(&[&a, &b], Axis::Cols),
+        Matrix::hstack(&[&a, &b])
+    );
+    assert_eq!(
+        Matrix::concat(&[&a, &c], Axis::Rows),
+        Matrix::vstack(&[&a, &c])
+    );
+    assert_eq!(Matrix::hstack(&[&a]), a);
+}
+
+#[test]
+fn block() {
+    let a: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    let b: Matrix<i32> = Matrix::from_iter(2, 3, 10..);
+    let c: Matrix<i32> = Matrix::from_iter(1, 2, vec![0, 0]);
+    let d: Matrix<i32> = Matrix::from_iter(1, 3, vec![-1, -2, -3]);
+
+    let mat = Matrix::block(&[&[&a, &b], &[&c, &d]]);
+    assert_eq!(
+        mat,
+        Matrix::from_iter(
+            3,
+            5,
+            vec![1, 2, 10, 11, 12, 3, 4, 13, 14, 15, 0, 0, -1, -2, -3]
+        )
+    );
+
+    let blocks = mat.split_blocks(&[2, 1], &[2, 3]);
+    assert_eq!(blocks, vec![vec![a, b], vec![c, d]]);
+
+    let cells = mat.split_blocks(&[1, 1, 1], &[5]);
+    assert_eq!(cells.len(), 3);
+    assert_eq!(cells[2][0], Matrix::from_iter(1, 5, vec![0, 0, -1, -2, -3]));
+}
+
+#[test]
+#[should_panic(expected = "block (1, 0) is 1x3, expected 1x2")]
+fn block_shapes() {
+    let a: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    let b: Matrix<i32> = Matrix::from_iter(2, 1, 1..);
+    let c: Matrix<i32> = Matrix::from_iter(1, 3, 1..);
+    let d: Matrix<i32> = Matrix::from_iter(1, 1, 1..);
+
+    Matrix::block(&[&[&a, &b], &[&c, &d]]);
+}
+
+#[test]
+#[should_panic(expected = "matrix 1 has 1 rows, expected 2")]
+fn hstack_rows() {
+    let a: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    let b: Matrix<i32> = Matrix::from_iter(1, 2, 1..);
+
+    Matrix::hstack(&[&a, &b]);
+}
+
+#[test]
+#[should_panic]
+fn split_sizes() {
+    let mat: Matrix<i32> = Matrix::from_iter(3, 3, 1..);
+    mat.split_blocks(&[1, 1], &[3]);
+}
@@ -0,0 +1,152 @@
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use simple_matrix::iterative::LinearOperator;
+use simple_matrix::{Diagonal, LowerTriangular, Matrix, Permutation, Symmetric, UpperTriangular};
+
+/// A square matrix, a matrix to multiply on both sides, and a permutation.
+#[derive(Debug, Clone)]
+struct AStructured(Matrix<i64>, Matrix<i64>, Vec<usize>);
+
+impl Arbitrary for AStructured {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let s = std::cmp::max(2, g.size() / 4); // rows & cols != 0
+
+        let len = g.gen_range(1, s);
+        let cols = g.gen_range(1, s);
+        let mut perm: Vec<usize> = (0..len).collect();
+        for i in (1..len).rev() {
+            perm.swap(i, g.gen_range(0, i + 1));
+        }
+
+        AStructured(
+            Matrix::from_iter(len, len, (0..).map(|_| g.gen_range(-100, 100))),
+            Matrix::from_iter(len, cols, (0..).map(|_| g.gen_range(-100, 100))),
+            perm,
+        )
+    }
+}
+
+/// Keep the cells of a square matrix for which `keep(row, col)` holds.
+fn mask(mat: &Matrix<i64>, keep: impl Fn(usize, usize) -> bool) -> Matrix<i64> {
+    let len = mat.rows();
+    Matrix::from_iter(
+        len,
+        len,
+        (0..len * len).map(|i| {
+            if keep(i / len, i % len) {
+                mat[(i / len, i % len)]
+            } else {
+                0
+            }
+        }),
+    )
+}
+
+#[test]
+fn storage() {
+    let dense: Matrix<i32> = Matrix::from_iter(3, 3, 1..);
+
+    let upper = UpperTriangular::from(&dense);
+    assert_eq!(
+        upper.to_dense(),
+        Matrix::from_iter(3, 3, vec![1, 2, 3, 0, 5, 6, 0, 0, 9])
+    );
+    assert_eq!(upper.get(0, 2), Some(&3));
+    assert_eq!(upper.get(2, 0), None);
+    assert_eq!(upper.determinant(), 45);
+    assert_eq!(upper.transpose().to_dense(), upper.to_dense().transpose());
+
+    let mut lower = LowerTriangular::from(&dense);
+    assert_eq!(
+        lower.to_dense(),
+        Matrix::from_iter(3, 3, vec![1, 0, 0, 4, 5, 0, 7, 8, 9])
+    );
+    assert!(!lower.set(0, 1, 1));
+    assert!(lower.set(1, 0, 1));
+    assert_eq!(lower.transpose().get(0, 1), Some(&1));
+
+    let mut sym = Symmetric::from(&dense);
+    assert_eq!(
+        sym.to_dense(),
+        Matrix::from_iter(3, 3, vec![1, 4, 7, 4, 5, 8, 7, 8, 9])
+    );
+    assert!(sym.set(0, 2, 0));
+    assert_eq!(sym.get(2, 0), Some(&0));
+    assert_eq!(sym.get(3, 0), None);
+
+    let p = Permutation::new(vec![1, 2, 0]);
+    assert_eq!(p.sign(), 1);
+    assert_eq!(Permutation::new(vec![1, 0, 2]).sign(), -1);
+    assert_eq!(&p * &p.inverse(), Permutation::identity(3));
+
+    let mut y = vec![0; 3];
+    p.mul_vec(&[10, 20, 30], &mut y);
+    assert_eq!(y, vec![20, 30, 10]);
+}
+
+#[test]
+#[should_panic]
+fn invalid_permutation() {
+    Permutation::new(vec![0, 2, 2]);
+}
+
+#[test]
+fn triangular_solve() {
+    let mat: Matrix<f64> = Matrix::from_iter(4, 4, (1..).map(f64::from));
+    let b: Matrix<f64> = Matrix::from_iter(4, 2, (0..).map(|n| f64::from(n).sin()));
+
+    let lower = LowerTriangular::from(&mat);
+    let x = lower.solve(&b).unwrap();
+    assert!((&lower * &x).approx_eq(&b, 1e-12, 1e-12));
+
+    let upper = UpperTriangular::from(&mat);
+    let x = upper.solve(&b).unwrap();
+    assert!((&upper * &x).approx_eq(&b, 1e-12, 1e-12));
+
+    let mut singular = upper;
+    singular.set(2, 2, 0.0);
+    assert!(singular.solve(&b).is_none());
+}
+
+quickcheck! {
+    fn qcheck_products(t: AStructured) -> bool {
+        let (a, b) = (&t.0, &t.1);
+        let bt = b.transpose();
+
+        let diag = Diagonal::new((0..a.rows()).map(|i| a[(i, i)]).collect());
+        let upper = UpperTriangular::from(a);
+        let lower = LowerTriangular::from(a);
+        let sym = Symmetric::from(a);
+        let dense_diag = mask(a, |r, c| r == c);
+        let dense_upper = mask(a, |r, c| r <= c);
+        let dense_lower = mask(a, |r, c| r >= c);
+        let dense_sym = &dense_lower + &mask(a, |r, c| r > c).transpose();
+
+        diag.to_dense() == dense_diag
+        && upper.to_dense() == dense_upper
+        && lower.to_dense() == dense_lower
+        && sym.to_dense() == dense_sym
+        && &diag * b == &dense_diag * b
+        && &bt * &diag == &bt * &dense_diag
+        && (&diag * &diag).to_dense() == &dense_diag * &dense_diag
+        && &upper * b == &dense_upper * b
+        && &bt * &upper == &bt * &dense_upper
+        && &lower * b == &dense_lower * b
+        && &bt * &lower == &bt * &dense_lower
+        && &sym * b == &dense_sym * b
+        && &bt * &sym == &bt * &dense_sym
+    }
+
+    fn qcheck_permutation(t: AStructured) -> bool {
+        let (b, perm) = (&t.1, t.2);
+        let p = Permutation::new(perm);
+        let dense: Matrix<i64> = p.to_dense();
+        let bt = b.transpose();
+        let q = p.inverse();
+
+        &p * b == &dense * b
+        && &bt * &p == &bt * &dense
+        && (&p * &q).to_dense::<i64>() == &dense * &q.to_dense()
+        && q.to_dense::<i64>() == dense.transpose()
+        && dense.determinant() == Some(i64::from(p.sign()))
+    }
+}
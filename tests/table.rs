@@ -0,0 +1,104 @@
+use simple_matrix::{LatexEnvironment, Matrix, TableOptions};
+
+fn labels(labels: &[&str]) -> Option<Vec<String>> {
+    Some(labels.iter().map(|l| l.to_string()).collect())
+}
+
+#[test]
+fn latex() {
+    let mat: Matrix<i32> = Matrix::from_iter(2, 3, 1..);
+    let options = TableOptions::default();
+
+    assert_eq!(
+        mat.to_latex(LatexEnvironment::PMatrix, &options),
+        "\\begin{pmatrix}\n1 & 2 & 3 \\\\\n4 & 5 & 6\n\\end{pmatrix}\n"
+    );
+    assert_eq!(
+        mat.to_latex(LatexEnvironment::Tabular, &options),
+        "\\begin{tabular}{rrr}\n1 & 2 & 3 \\\\\n4 & 5 & 6 \\\\\n\\end{tabular}\n"
+    );
+
+    let options = TableOptions {
+        row_labels: labels(&["x_1", "x_2"]),
+        col_labels: labels(&["a", "b & c", "50%"]),
+        format: Some(Box::new(|v: &i32| format!("\\num{{{}}}", v * 10))),
+    };
+    assert_eq!(
+        mat.to_latex(LatexEnvironment::Tabular, &options),
+        "\\begin{tabular}{l|rrr}\n\
+         \x20& a & b \\& c & 50\\% \\\\\n\
+         \\hline\n\
+         x\\_1 & \\num{10} & \\num{20} & \\num{30} \\\\\n\
+         x\\_2 & \\num{40} & \\num{50} & \\num{60} \\\\\n\
+         \\end{tabular}\n"
+    );
+    // Labels are ignored by the matrix environments
+    assert_eq!(
+        mat.to_latex(LatexEnvironment::BMatrix, &options),
+        "\\begin{bmatrix}\n\\num{10} & \\num{20} & \\num{30} \\\\\n\\num{40} & \\num{50} & \\num{60}\n\\end{bmatrix}\n"
+    );
+}
+
+#[test]
+fn markdown() {
+    let mat: Matrix<f64> = Matrix::from_iter(2, 2, vec![1.0, 0.25, -3.5, 1e3]);
+
+    assert_eq!(
+        mat.to_markdown_table(&TableOptions::default()),
+        "|   |   |\n|--:|--:|\n| 1 | 0.25 |\n| -3.5 | 1000 |\n"
+    );
+
+    let options = TableOptions {
+        col_labels: labels(&["a|b", "c"]),
+        format: Some(Box::new(|v: &f64| format!("{:.1}", v))),
+        ..TableOptions::default()
+    };
+    assert_eq!(
+        mat.to_markdown_table(&options),
+        "| a\\|b | c |\n|--:|--:|\n| 1.0 | 0.2 |\n| -3.5 | 1000.0 |\n"
+    );
+}
+
+#[test]
+fn html() {
+    let mat = Matrix::from_iter(1, 2, vec!["<b>", "&"]);
+    let options = TableOptions {
+        row_labels: labels(&["r\"1\""]),
+        col_labels: labels(&["x", "y"]),
+        ..TableOptions::default()
+    };
+
+    assert_eq!(
+        mat.to_html_table(&options),
+        "<table>\n\
+         \x20 <thead>\n\
+         \x20   <tr><th></th><th>x</th><th>y</th></tr>\n\
+         \x20 </thead>\n\
+         \x20 <tbody>\n\
+         \x20   <tr><th>r&quot;1&quot;</th><td>&lt;b&gt;</td><td>&amp;</td></tr>\n\
+         \x20 </tbody>\n\
+         </table>\n"
+    );
+}
+
+#[test]
+#[should_panic]
+fn wrong_labels() {
+    let mat: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    let options = TableOptions {
+        row_labels: labels(&["only one"]),
+        ..TableOptions::default()
+    };
+    mat.to_html_table(&options);
+}
+
+#[test]
+#[should_panic]
+fn wrong_labels_latex_matrix() {
+    let mat: Matrix<i32> = Matrix::from_iter(2, 2, 1..);
+    let options = TableOptions {
+        col_labels: labels(&["a", "b", "c"]),
+        ..TableOptions::default()
+    };
+    mat.to_latex(LatexEnvironment::PMatrix, &options);
+}